@@ -0,0 +1,198 @@
+use clap::ValueEnum;
+use kanjidic_types::{radical::RadicalKind, Character, Codepoint, Language, QueryCode, Reading};
+
+/// Separates multiple values within a single cell.
+const SEPARATOR: &str = ";";
+
+/// A field of a character flattened into a single text cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Column {
+    /// The kanji itself
+    Literal,
+    /// The Unicode codepoint in hexadecimal
+    Unicode,
+    /// The KANJIDIC2 grade number
+    Grade,
+    /// The accepted stroke count
+    StrokeCount,
+    /// The newspaper frequency ranking
+    Frequency,
+    /// The pre-2010 JLPT level
+    Jlpt,
+    /// The classical KangXi radical number
+    Radical,
+    /// Names of the kanji as a radical
+    RadicalNames,
+    /// Onyomi readings in katakana
+    Onyomi,
+    /// Kunyomi readings in KANJIDIC2 notation
+    Kunyomi,
+    /// Mandarin readings in pinyin
+    PinYin,
+    /// Korean readings in Hangul
+    Korean,
+    /// Vietnamese readings
+    Vietnam,
+    /// Meanings in the selected language
+    Meanings,
+    /// Readings used in names
+    Nanori,
+    /// The SKIP code
+    Skip,
+    /// The Four Corner code
+    FourCorner,
+    /// The constituent radicals
+    Decomposition,
+}
+
+impl Column {
+    /// The column heading.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Column::Literal => "literal",
+            Column::Unicode => "unicode",
+            Column::Grade => "grade",
+            Column::StrokeCount => "stroke_count",
+            Column::Frequency => "frequency",
+            Column::Jlpt => "jlpt",
+            Column::Radical => "radical",
+            Column::RadicalNames => "radical_names",
+            Column::Onyomi => "onyomi",
+            Column::Kunyomi => "kunyomi",
+            Column::PinYin => "pin_yin",
+            Column::Korean => "korean",
+            Column::Vietnam => "vietnam",
+            Column::Meanings => "meanings",
+            Column::Nanori => "nanori",
+            Column::Skip => "skip",
+            Column::FourCorner => "four_corner",
+            Column::Decomposition => "decomposition",
+        }
+    }
+
+    /// The text of the cell for the given character,
+    /// with meanings taken from the given language.
+    pub fn value(&self, character: &Character, language: Language) -> String {
+        match self {
+            Column::Literal => character.literal.to_string(),
+            Column::Unicode => character
+                .codepoints
+                .iter()
+                .find_map(|codepoint| match codepoint {
+                    Codepoint::Unicode(code) => Some(format!("{:x}", code)),
+                    _ => None,
+                })
+                .unwrap_or_default(),
+            Column::Grade => optional(character.grade.map(|grade| grade.level())),
+            Column::StrokeCount => character.stroke_counts.accepted.to_string(),
+            Column::Frequency => optional(character.frequency),
+            Column::Jlpt => optional(character.jlpt),
+            Column::Radical => optional(
+                character
+                    .radicals
+                    .iter()
+                    .find(|radical| radical.kind == RadicalKind::Classical)
+                    .map(|radical| radical.radical as u8),
+            ),
+            Column::RadicalNames => character.radical_names.join(SEPARATOR),
+            Column::Onyomi => readings(character, |reading| match reading {
+                Reading::Onyomi(onyomi) => Some(onyomi.reading.clone()),
+                _ => None,
+            }),
+            Column::Kunyomi => readings(character, |reading| match reading {
+                Reading::Kunyomi(kunyomi) => Some(kunyomi.to_string()),
+                _ => None,
+            }),
+            Column::PinYin => readings(character, |reading| match reading {
+                Reading::PinYin(pin_yin) => Some(pin_yin.to_string()),
+                _ => None,
+            }),
+            Column::Korean => readings(character, |reading| match reading {
+                Reading::KoreanHangul(hangul) => Some(hangul.clone()),
+                _ => None,
+            }),
+            Column::Vietnam => readings(character, |reading| match reading {
+                Reading::Vietnam(vietnam) => Some(vietnam.clone()),
+                _ => None,
+            }),
+            Column::Meanings => character
+                .translations()
+                .get(&language)
+                .map(|meanings| meanings.join(SEPARATOR))
+                .unwrap_or_default(),
+            Column::Nanori => character.nanori.join(SEPARATOR),
+            Column::Skip => character
+                .query_codes
+                .iter()
+                .find_map(|query_code| match query_code {
+                    QueryCode::Skip(skip) => Some(skip.to_string()),
+                    _ => None,
+                })
+                .unwrap_or_default(),
+            Column::FourCorner => character
+                .query_codes
+                .iter()
+                .filter_map(|query_code| match query_code {
+                    QueryCode::FourCorner(four_corner) => Some(four_corner.to_string()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join(SEPARATOR),
+            Column::Decomposition => character.decomposition.iter().collect(),
+        }
+    }
+}
+
+/// The columns written when none are selected.
+pub const DEFAULT_COLUMNS: &[Column] = &[
+    Column::Literal,
+    Column::Grade,
+    Column::StrokeCount,
+    Column::Frequency,
+    Column::Jlpt,
+    Column::Onyomi,
+    Column::Kunyomi,
+    Column::Meanings,
+];
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn readings<F>(character: &Character, f: F) -> String
+where
+    F: Fn(&Reading) -> Option<String>,
+{
+    character
+        .readings()
+        .filter_map(f)
+        .collect::<Vec<_>>()
+        .join(SEPARATOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Column;
+    use kanjidic_types::{Character, Language};
+
+    #[test]
+    fn values() {
+        let character = Character::sample();
+        let values: Vec<_> = [
+            Column::Literal,
+            Column::Grade,
+            Column::Frequency,
+            Column::Onyomi,
+            Column::Kunyomi,
+            Column::Meanings,
+        ]
+        .iter()
+        .map(|column| column.value(&character, Language::En))
+        .collect();
+        assert_eq!(
+            values,
+            vec!["亜", "", "1509", "ア", "つ.ぐ", "Asia;rank next"]
+        );
+        assert_eq!(Column::Meanings.value(&character, Language::Fr), "");
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{column::Column, KdcError};
+use clap::ValueEnum;
+use kanjidic_types::{Character, Filter, Language, Reference};
+use std::{fs::File, io::Write, path::Path, str::FromStr};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TemplateError {
+    #[error("(Template) Unknown field {{{0}}}")]
+    UnknownField(String),
+    #[error("(Template) Unclosed brace")]
+    Unclosed,
+}
+
+/// Text for one side of a card, with `{field}` placeholders
+/// replaced by the values of the named columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template(Vec<Part>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Part {
+    Text(String),
+    Field(Column),
+}
+
+impl FromStr for Template {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = vec![];
+        let mut rest = s;
+        while let Some(start) = rest.find('{') {
+            if start > 0 {
+                parts.push(Part::Text(rest[..start].to_owned()));
+            }
+            let end = rest[start..].find('}').ok_or(TemplateError::Unclosed)? + start;
+            let name = &rest[start + 1..end];
+            let column = Column::value_variants()
+                .iter()
+                .find(|column| column.name() == name)
+                .ok_or_else(|| TemplateError::UnknownField(name.to_owned()))?;
+            parts.push(Part::Field(*column));
+            rest = &rest[end + 1..];
+        }
+        if !rest.is_empty() {
+            parts.push(Part::Text(rest.to_owned()));
+        }
+        Ok(Self(parts))
+    }
+}
+
+impl Template {
+    /// Fills in the placeholders for the given character,
+    /// with meanings taken from the given language.
+    /// The template itself may hold markup, but the values are escaped
+    /// since the deck is imported as HTML.
+    pub fn render(&self, character: &Character, language: Language) -> String {
+        self.0
+            .iter()
+            .map(|part| match part {
+                Part::Text(text) => text.clone(),
+                Part::Field(column) => escape(&column.value(character, language)),
+            })
+            .collect()
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Which characters make up a deck and how its cards look.
+pub struct Options<'a> {
+    /// The front of each card
+    pub front: &'a Template,
+    /// The back of each card
+    pub back: &'a Template,
+    /// Which characters to include
+    pub filter: &'a Filter,
+    /// The dr_type of a reference to order cards by.
+    /// Characters without the reference are left out.
+    pub order: Option<&'a str>,
+    /// The language of meanings
+    pub language: Language,
+    /// The name of the deck to import into
+    pub deck: Option<&'a str>,
+}
+
+/// Writes a tab-separated file of cards that Anki can import,
+/// with a header describing the layout.
+pub fn write(path: &Path, characters: &[Character], options: &Options) -> Result<(), KdcError> {
+    let mut file = File::create(path)?;
+    writeln!(file, "#separator:tab")?;
+    writeln!(file, "#html:true")?;
+    writeln!(file, "#columns:Front\tBack")?;
+    if let Some(deck) = options.deck {
+        writeln!(file, "#deck:{}", deck)?;
+    }
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(b'\t')
+        .has_headers(false)
+        .from_writer(file);
+    for character in cards(characters, options) {
+        writer.write_record(&[
+            options.front.render(character, options.language),
+            options.back.render(character, options.language),
+        ])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// The characters to write cards for, in order.
+pub fn cards<'a>(characters: &'a [Character], options: &Options) -> Vec<&'a Character> {
+    let included = characters
+        .iter()
+        .filter(|character| options.filter.matches(character));
+    match options.order {
+        Some(kind) => {
+            let mut ordered: Vec<_> = included
+                .filter_map(|character| {
+                    character
+                        .references
+                        .iter()
+                        .find(|reference| reference.kind() == kind)
+                        .map(|reference| (reference, character))
+                })
+                .collect();
+            ordered.sort_by(|(a, _), (b, _)| Reference::cmp(a, b));
+            ordered
+                .into_iter()
+                .map(|(_, character)| character)
+                .collect()
+        }
+        None => included.collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cards, Options, Template, TemplateError};
+    use kanjidic_types::{
+        character::ReadingMeaningGroup, Character, Filter, Grade, Language, Reference,
+    };
+
+    #[test]
+    fn template() {
+        let template: Template = "{literal} has {stroke_count} strokes".parse().unwrap();
+        let character = Character::sample();
+        assert_eq!(
+            template.render(&character, Language::En),
+            "亜 has 7 strokes"
+        );
+        assert_eq!(
+            "{strokes}".parse::<Template>(),
+            Err(TemplateError::UnknownField("strokes".into()))
+        );
+        assert_eq!("{literal".parse::<Template>(), Err(TemplateError::Unclosed));
+    }
+
+    #[test]
+    fn escapes_values() {
+        let character = Character {
+            reading_meaning_groups: vec![ReadingMeaningGroup::test(
+                vec![],
+                &[(Language::En, "<b>Asia</b> & more")],
+            )],
+            ..Character::test('亜')
+        };
+        let template: Template = "<b>{meanings}</b>".parse().unwrap();
+        assert_eq!(
+            template.render(&character, Language::En),
+            "<b>&lt;b&gt;Asia&lt;/b&gt; &amp; more</b>"
+        );
+    }
+
+    #[test]
+    fn filters_and_orders() {
+        let characters = vec![
+            Character {
+                grade: Some(Grade::Kyouiku(1)),
+                references: vec![Reference::Heisig6(1)],
+                ..Character::test('一')
+            },
+            Character {
+                grade: Some(Grade::Jouyou),
+                references: vec![Reference::Heisig6(1616)],
+                ..Character::test('亜')
+            },
+            Character {
+                grade: Some(Grade::Kyouiku(1)),
+                ..Character::test('右')
+            },
+            Character {
+                grade: Some(Grade::Kyouiku(1)),
+                references: vec![Reference::Heisig6(1218)],
+                ..Character::test('円')
+            },
+        ];
+        let template: Template = "{literal}".parse().unwrap();
+        let filter = Filter {
+            grades: vec![1],
+            ..Default::default()
+        };
+        let options = Options {
+            front: &template,
+            back: &template,
+            filter: &filter,
+            order: Some("heisig6"),
+            language: Language::En,
+            deck: None,
+        };
+        let literals: Vec<_> = cards(&characters, &options)
+            .iter()
+            .map(|character| character.literal)
+            .collect();
+        assert_eq!(literals, vec!['一', '円']);
+    }
+}
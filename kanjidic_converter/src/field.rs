@@ -0,0 +1,121 @@
+use kanjidic_types::{Character, Field, Language};
+use serde_json::{Map, Value};
+
+/// The literal of the character along with the selected fields.
+/// Readings and translations are merged across reading and meaning groups,
+/// and translations are limited to the given languages unless there are none.
+/// `Field::All` selects every field.
+pub fn project(character: &Character, fields: &[Field], languages: &[Language]) -> Value {
+    let fields = if fields.contains(&Field::All) {
+        &Field::EVERY[..]
+    } else {
+        fields
+    };
+    let mut out = Map::new();
+    out.insert("literal".into(), character.literal.to_string().into());
+    for field in fields {
+        let (key, value) = match field {
+            Field::All => continue,
+            Field::Codepoints => ("codepoints", to_value(&character.codepoints)),
+            Field::Radicals => ("radicals", to_value(&character.radicals)),
+            Field::Grade => ("grade", to_value(&character.grade)),
+            Field::StrokeCounts => ("strokeCounts", to_value(&character.stroke_counts)),
+            Field::Variants => ("variants", to_value(&character.variants)),
+            Field::Frequency => ("frequency", to_value(&character.frequency)),
+            Field::RadicalNames => ("radicalNames", to_value(&character.radical_names)),
+            Field::Jlpt => ("jlpt", to_value(&character.jlpt)),
+            Field::References => ("references", to_value(&character.references)),
+            Field::QueryCodes => ("queryCodes", to_value(&character.query_codes)),
+            Field::Readings => (
+                "readings",
+                to_value(&character.readings().collect::<Vec<_>>()),
+            ),
+            Field::Translations => {
+                let mut translations = character.translations();
+                if !languages.is_empty() {
+                    translations.retain(|language, _| languages.contains(language));
+                }
+                ("translations", to_value(&translations))
+            }
+            Field::Nanori => ("nanori", to_value(&character.nanori)),
+            Field::Decomposition => ("decomposition", to_value(&character.decomposition)),
+        };
+        if !is_empty(&value) {
+            out.insert(key.into(), value);
+        }
+    }
+    Value::Object(out)
+}
+
+fn to_value<T: serde::Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).expect("Characters serialize to JSON")
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(array) => array.is_empty(),
+        Value::Object(object) => object.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::project;
+    use kanjidic_types::{
+        character::{ReadingMeaningGroup, Translations},
+        Character, Field, Language, Reading, StrokeCount,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn projects_fields() {
+        let mut translations = Translations::default();
+        translations.insert(Language::En, vec!["Asia".into()]);
+        translations.insert(Language::Fr, vec!["Asie".into()]);
+        let character = Character {
+            stroke_counts: StrokeCount {
+                accepted: 7,
+                miscounts: vec![],
+            },
+            frequency: Some(1509),
+            reading_meaning_groups: vec![ReadingMeaningGroup {
+                readings: vec![Reading::Vietnam("A".into())],
+                translations,
+            }],
+            ..Character::test('亜')
+        };
+        let fields = [
+            Field::Frequency,
+            Field::Grade,
+            Field::Readings,
+            Field::Translations,
+        ];
+        assert_eq!(
+            project(&character, &fields, &[Language::Fr]),
+            json!({
+                "literal": "亜",
+                "frequency": 1509,
+                "readings": [{ "tag": "Vietnam", "content": "A" }],
+                "translations": { "fr": ["Asie"] },
+            })
+        );
+    }
+
+    #[test]
+    fn all_selects_every_field() {
+        let character = Character {
+            frequency: Some(1509),
+            ..Character::test('亜')
+        };
+        assert_eq!(
+            project(&character, &[Field::All], &[]),
+            project(&character, &Field::EVERY, &[])
+        );
+        assert_eq!(
+            project(&character, &[Field::All], &[])["frequency"],
+            json!(1509)
+        );
+    }
+}
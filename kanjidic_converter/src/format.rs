@@ -0,0 +1,261 @@
+use crate::{column::Column, field, sqlite, KdcError};
+use clap::ValueEnum;
+use kanjidic_parser::kanjidic::Kanjidic;
+use kanjidic_types::{Character, Field, Language, Snapshot};
+use serde::Serialize;
+use serde_json::Value;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// The kind of file to write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Pretty-printed JSON
+    Json,
+    /// JSON without whitespace
+    JsonCompact,
+    /// JSON Lines, one character per line
+    Jsonl,
+    /// Comma-separated values with the selected columns
+    Csv,
+    /// Tab-separated values with the selected columns
+    Tsv,
+    /// MessagePack with named fields
+    Msgpack,
+    /// CBOR
+    Cbor,
+    /// A binary snapshot with prebuilt indexes, as loaded by kanjidic_server
+    Snapshot,
+    /// A SQLite database with a table for each part of a character
+    /// and full-text search over meanings
+    Sqlite,
+}
+
+/// The formats that can be written to any writer, which is all but SQLite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stream {
+    Json,
+    JsonCompact,
+    Jsonl,
+    Csv,
+    Tsv,
+    Msgpack,
+    Cbor,
+    Snapshot,
+}
+
+impl Format {
+    /// The format as a stream, or `None` for SQLite,
+    /// which needs a path to open a database at.
+    fn stream(self) -> Option<Stream> {
+        match self {
+            Format::Json => Some(Stream::Json),
+            Format::JsonCompact => Some(Stream::JsonCompact),
+            Format::Jsonl => Some(Stream::Jsonl),
+            Format::Csv => Some(Stream::Csv),
+            Format::Tsv => Some(Stream::Tsv),
+            Format::Msgpack => Some(Stream::Msgpack),
+            Format::Cbor => Some(Stream::Cbor),
+            Format::Snapshot => Some(Stream::Snapshot),
+            Format::Sqlite => None,
+        }
+    }
+}
+
+/// How to lay out the output beyond the choice of format.
+pub struct Options<'a> {
+    /// Whether to include the file header in whole-document formats
+    pub header: bool,
+    /// The columns to include in tabular formats
+    pub columns: &'a [Column],
+    /// The language of meanings in tabular formats
+    pub language: Language,
+    /// The fields to keep in JSON, JSON Lines, MessagePack and CBOR,
+    /// or all of them if empty
+    pub fields: &'a [Field],
+    /// The languages of translations to keep when fields are selected,
+    /// or all of them if empty
+    pub languages: &'a [Language],
+}
+
+/// The contents of whole-document formats.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Document<'a> {
+    Whole(&'a Kanjidic),
+    Characters(&'a [Character]),
+    Projected(Vec<Value>),
+}
+
+/// Writes the file to the given path in the given format.
+pub fn write(
+    path: &Path,
+    format: Format,
+    kanjidic: Kanjidic,
+    options: &Options,
+) -> Result<(), KdcError> {
+    let stream = match format.stream() {
+        Some(stream) => stream,
+        None => return sqlite::write(path, &kanjidic),
+    };
+    let mut out = BufWriter::new(File::create(path)?);
+    write_stream(&mut out, stream, kanjidic, options)?;
+    out.flush()?;
+    Ok(())
+}
+
+fn write_stream<W: Write>(
+    w: &mut W,
+    stream: Stream,
+    kanjidic: Kanjidic,
+    options: &Options,
+) -> Result<(), KdcError> {
+    let project = |character| field::project(character, options.fields, options.languages);
+    let document = if !options.fields.is_empty() {
+        Document::Projected(kanjidic.characters.iter().map(project).collect())
+    } else if options.header {
+        Document::Whole(&kanjidic)
+    } else {
+        Document::Characters(&kanjidic.characters)
+    };
+    match stream {
+        Stream::Json => serde_json::to_writer_pretty(&mut *w, &document)
+            .map_err(|err| KdcError::Json(err.into()))?,
+        Stream::JsonCompact => {
+            serde_json::to_writer(&mut *w, &document).map_err(|err| KdcError::Json(err.into()))?
+        }
+        Stream::Jsonl => {
+            for character in kanjidic.characters.iter() {
+                let json = if options.fields.is_empty() {
+                    serde_json::to_writer(&mut *w, character)
+                } else {
+                    serde_json::to_writer(&mut *w, &project(character))
+                };
+                json.map_err(|err| KdcError::Json(err.into()))?;
+                writeln!(w)?;
+            }
+        }
+        Stream::Csv => table(w, b',', &kanjidic, options)?,
+        Stream::Tsv => table(w, b'\t', &kanjidic, options)?,
+        Stream::Msgpack => rmp_serde::encode::write_named(w, &document)?,
+        Stream::Cbor => ciborium::into_writer(&document, w)?,
+        Stream::Snapshot => w.write_all(&Snapshot::new(kanjidic.characters).to_bytes()?)?,
+    }
+    Ok(())
+}
+
+fn table<W: Write>(
+    w: &mut W,
+    delimiter: u8,
+    kanjidic: &Kanjidic,
+    options: &Options,
+) -> Result<(), KdcError> {
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .from_writer(w);
+    writer.write_record(options.columns.iter().map(|column| column.name()))?;
+    for character in kanjidic.characters.iter() {
+        writer.write_record(
+            options
+                .columns
+                .iter()
+                .map(|column| column.value(character, options.language)),
+        )?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_stream, Format, Options, Stream};
+    use crate::column::DEFAULT_COLUMNS;
+    use clap::ValueEnum;
+    use kanjidic_parser::kanjidic::{Kanjidic, Strictness};
+    use kanjidic_types::{Field, Language};
+
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<kanjidic2>
+<header>
+<file_version>4</file_version>
+<database_version>2021-176</database_version>
+<date_of_creation>2021-06-25</date_of_creation>
+</header>
+<character>
+<literal>亜</literal>
+<codepoint><cp_value cp_type="ucs">4e9c</cp_value></codepoint>
+<radical><rad_value rad_type="classical">7</rad_value></radical>
+<misc><grade>8</grade><stroke_count>7</stroke_count><freq>1509</freq></misc>
+<reading_meaning>
+<rmgroup>
+<reading r_type="ja_on">ア</reading>
+<meaning m_lang="pt">Ásia</meaning>
+<meaning m_lang="fr">Asie</meaning>
+<meaning>Asia</meaning>
+<meaning m_lang="es">Asia</meaning>
+</rmgroup>
+<nanori>つぐ</nanori>
+</reading_meaning>
+</character>
+<character>
+<literal>唖</literal>
+<codepoint><cp_value cp_type="ucs">5516</cp_value></codepoint>
+<radical><rad_value rad_type="classical">30</rad_value></radical>
+<misc><stroke_count>10</stroke_count></misc>
+<reading_meaning>
+<rmgroup>
+<reading r_type="ja_on">ア</reading>
+<meaning m_lang="fr">muet</meaning>
+<meaning>mute</meaning>
+</rmgroup>
+</reading_meaning>
+</character>
+</kanjidic2>
+"#;
+
+    fn convert(stream: Stream, fields: &[Field]) -> Vec<u8> {
+        let kanjidic = Kanjidic::parse(XML, Strictness::Strict).unwrap().kanjidic;
+        let options = Options {
+            header: true,
+            columns: DEFAULT_COLUMNS,
+            language: Language::En,
+            fields,
+            languages: &[],
+        };
+        let mut out = vec![];
+        write_stream(&mut out, stream, kanjidic, &options).unwrap();
+        out
+    }
+
+    #[test]
+    fn deterministic() {
+        for stream in Format::value_variants()
+            .iter()
+            .filter_map(|format| format.stream())
+        {
+            for fields in [&[][..], &[Field::Translations]].iter() {
+                assert_eq!(
+                    convert(stream, fields),
+                    convert(stream, fields),
+                    "{:?}",
+                    stream
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn orders_languages() {
+        let json = String::from_utf8(convert(Stream::JsonCompact, &[])).unwrap();
+        let positions: Vec<_> = ["\"en\"", "\"es\"", "\"fr\"", "\"pt\""]
+            .iter()
+            .map(|code| json.find(code).unwrap())
+            .collect();
+        let mut sorted = positions.clone();
+        sorted.sort_unstable();
+        assert_eq!(positions, sorted);
+    }
+}
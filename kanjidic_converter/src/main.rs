@@ -1,44 +1,436 @@
-use clap::Parser;
-use kanjidic_parser::kanjidic::{self, Kanjidic};
-use std::{convert::TryFrom, fs};
+mod column;
+mod deck;
+mod field;
+mod format;
+mod sqlite;
+mod stats;
+
+use clap::{Args, Parser, Subcommand};
+use column::{Column, DEFAULT_COLUMNS};
+use deck::{Template, TemplateError};
+use format::Format;
+use kanjidic_parser::{
+    diff,
+    kanjidic::{self, Kanjidic, Strictness},
+};
+use kanjidic_types::{delta, snapshot, Delta, Field, Filter, KangXi, Language};
+use stats::Stats;
+use std::{
+    convert::TryFrom,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
-enum KdcError {
+pub enum KdcError {
     #[error("Error reading or writing file: {0}")]
     Io(#[from] std::io::Error),
     #[error("Error parsing file: {0}")]
     Parse(#[from] kanjidic::Error),
-    #[error("Could not skip DTD section")]
-    DtdSkip,
-    #[error("Failed to get back UTF8 after skipping DTD: {0}")]
-    DtdSkipUtf8(#[from] std::str::Utf8Error),
     #[error("Error from json serialization: {0}")]
     Json(Box<dyn std::error::Error>),
+    #[error("Error writing snapshot: {0}")]
+    Snapshot(#[from] snapshot::Error),
+    #[error("Error writing delimited values: {0}")]
+    Csv(#[from] csv::Error),
+    #[error("Error writing MessagePack: {0}")]
+    Msgpack(#[from] rmp_serde::encode::Error),
+    #[error("Error writing CBOR: {0}")]
+    Cbor(#[from] ciborium::ser::Error<std::io::Error>),
+    #[error("Error writing SQLite database: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+    #[error("Error in card template: {0}")]
+    Template(#[from] TemplateError),
+    #[error("Error building delta: {0}")]
+    Delta(#[from] delta::Error),
 }
 
 #[derive(Parser)]
 struct Opts {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Convert the whole file to another format
+    Convert(ConvertOpts),
+    /// Write the characters that meet some criteria, optionally keeping only some fields
+    Filter(FilterOpts),
+    /// Print the entries for some characters as JSON
+    Lookup(LookupOpts),
+    /// Build a flashcard deck that Anki can import
+    Deck(DeckOpts),
+    /// Report what changed between two releases of the file
+    Diff(DiffOpts),
+    /// Write a package of the changes between two releases for updating a local copy
+    Delta(DeltaOpts),
+    /// Summarize the contents of the file
+    Stats(StatsOpts),
+}
+
+#[derive(Args)]
+struct Source {
     #[clap(short, long)]
     input: String,
+    /// Skip characters that fail to parse instead of aborting
     #[clap(short, long)]
-    output: String,
+    lenient: bool,
+}
+
+#[derive(Args)]
+struct Output {
     #[clap(short, long)]
+    output: String,
+    /// Include the file header in whole-document formats
+    #[clap(long)]
     header: bool,
+    /// The output format
+    #[clap(short, long, value_enum, default_value_t = Format::Json)]
+    format: Format,
+    /// Columns to write for CSV and TSV, separated by commas
+    #[clap(short, long, value_enum, value_delimiter = ',')]
+    columns: Vec<Column>,
+    /// The language of meanings for CSV and TSV
+    #[clap(long, default_value = "en")]
+    language: Language,
+}
+
+#[derive(Args)]
+struct FilterArgs {
+    /// Only include these grades, separated by commas
+    #[clap(short, long, value_delimiter = ',')]
+    grade: Vec<u8>,
+    /// Only include these JLPT levels, separated by commas
+    #[clap(short, long, value_delimiter = ',')]
+    jlpt: Vec<u8>,
+    /// Only include characters with at least this many strokes
+    #[clap(long)]
+    min_strokes: Option<u8>,
+    /// Only include characters with at most this many strokes
+    #[clap(long)]
+    max_strokes: Option<u8>,
+    /// Let common miscounts meet the stroke bounds
+    #[clap(long)]
+    miscounts: bool,
+    /// Only include characters ranked at or below this frequency
+    #[clap(long)]
+    min_frequency: Option<u16>,
+    /// Only include characters ranked at or above this frequency
+    #[clap(long)]
+    max_frequency: Option<u16>,
+    /// Only include these classical radical numbers, separated by commas
+    #[clap(long, value_delimiter = ',', value_parser = kangxi)]
+    radical: Vec<KangXi>,
+    /// Only include these Nelson radical numbers, separated by commas
+    #[clap(long, value_delimiter = ',', value_parser = kangxi)]
+    nelson_radical: Vec<KangXi>,
+    /// Only include characters with references of these dr_types, separated by commas
+    #[clap(long, value_delimiter = ',')]
+    has_reference: Vec<String>,
+    /// Only include characters with meanings in these languages, separated by commas
+    #[clap(long, value_delimiter = ',')]
+    has_language: Vec<Language>,
+    /// Only include characters with readings of these r_types, separated by commas
+    #[clap(long, value_delimiter = ',')]
+    has_reading: Vec<String>,
+    /// Only include characters containing all of these radical components
+    #[clap(long)]
+    component: Option<String>,
+}
+
+impl FilterArgs {
+    fn filter(self) -> Filter {
+        Filter {
+            grades: self.grade,
+            jlpt: self.jlpt,
+            min_strokes: self.min_strokes,
+            max_strokes: self.max_strokes,
+            miscounts: self.miscounts,
+            min_frequency: self.min_frequency,
+            max_frequency: self.max_frequency,
+            classical_radicals: self.radical,
+            nelson_radicals: self.nelson_radical,
+            references: self.has_reference,
+            languages: self.has_language,
+            readings: self.has_reading,
+            components: self.component.unwrap_or_default().chars().collect(),
+        }
+    }
+}
+
+fn kangxi(s: &str) -> Result<KangXi, String> {
+    let number: u8 = s.parse().map_err(|err| format!("{}", err))?;
+    KangXi::try_from(number).map_err(|err| format!("{}", err))
+}
+
+#[derive(Args)]
+struct Projection {
+    /// Fields to keep alongside the literal, separated by commas
+    #[clap(long, value_enum, value_delimiter = ',')]
+    fields: Vec<Field>,
+    /// Languages of translations to keep, separated by commas
+    #[clap(long, value_delimiter = ',')]
+    languages: Vec<Language>,
+}
+
+#[derive(Args)]
+struct ConvertOpts {
+    #[clap(flatten)]
+    source: Source,
+    #[clap(flatten)]
+    output: Output,
+}
+
+#[derive(Args)]
+struct FilterOpts {
+    #[clap(flatten)]
+    source: Source,
+    #[clap(flatten)]
+    output: Output,
+    #[clap(flatten)]
+    filter: FilterArgs,
+    #[clap(flatten)]
+    projection: Projection,
+}
+
+#[derive(Args)]
+struct LookupOpts {
+    #[clap(flatten)]
+    source: Source,
+    /// The characters to look up
+    literals: String,
+    #[clap(flatten)]
+    projection: Projection,
+}
+
+#[derive(Args)]
+struct DeckOpts {
+    #[clap(flatten)]
+    source: Source,
+    #[clap(short, long)]
+    output: String,
+    #[clap(flatten)]
+    filter: FilterArgs,
+    /// The front of each card, with fields named like {literal}
+    #[clap(long, default_value = "{literal}")]
+    front: String,
+    /// The back of each card, with fields named like {meanings}
+    #[clap(long, default_value = "{meanings}<br>{onyomi}<br>{kunyomi}")]
+    back: String,
+    /// Order cards by the reference with this dr_type, such as heisig6,
+    /// leaving out characters without it
+    #[clap(long)]
+    order: Option<String>,
+    /// The language of meanings
+    #[clap(long, default_value = "en")]
+    language: Language,
+    /// The name of the deck to import into
+    #[clap(short, long)]
+    deck: Option<String>,
+}
+
+#[derive(Args)]
+struct DiffOpts {
+    /// The older file
+    old: String,
+    /// The newer file
+    new: String,
+    /// Skip characters that fail to parse instead of aborting
+    #[clap(short, long)]
+    lenient: bool,
+    /// Print the report as JSON rather than text
+    #[clap(long)]
+    json: bool,
+}
+
+#[derive(Args)]
+struct DeltaOpts {
+    /// The older file
+    old: String,
+    /// The newer file
+    new: String,
+    #[clap(short, long)]
+    output: String,
+    /// Skip characters that fail to parse instead of aborting
+    #[clap(short, long)]
+    lenient: bool,
+}
+
+#[derive(Args)]
+struct StatsOpts {
+    #[clap(flatten)]
+    source: Source,
+    /// Print the summary as JSON rather than tables
+    #[clap(long)]
+    json: bool,
 }
 
 fn main() -> Result<(), KdcError> {
     let opts: Opts = Opts::parse();
-    let xml = std::fs::read_to_string(opts.input)?;
-    let start = xml.find("<kanjidic2>").ok_or(KdcError::DtdSkip)?;
-    let skipped = std::str::from_utf8(&xml.as_bytes()[start..])?;
-    let kanjidic = Kanjidic::try_from(skipped)?;
-    let json = if opts.header {
-        serde_json::to_string_pretty(&kanjidic)
+    match opts.command {
+        Command::Convert(opts) => {
+            let kanjidic = read(&opts.source)?;
+            write(kanjidic, &opts.output, &[], &[])
+        }
+        Command::Filter(opts) => filter(opts),
+        Command::Lookup(opts) => lookup(opts),
+        Command::Deck(opts) => deck(opts),
+        Command::Diff(opts) => diff(opts),
+        Command::Delta(opts) => delta(opts),
+        Command::Stats(opts) => stats(opts),
+    }
+}
+
+fn write(
+    kanjidic: Kanjidic,
+    output: &Output,
+    fields: &[Field],
+    languages: &[Language],
+) -> Result<(), KdcError> {
+    let columns = if output.columns.is_empty() {
+        DEFAULT_COLUMNS
+    } else {
+        &output.columns
+    };
+    let options = format::Options {
+        header: output.header,
+        columns,
+        language: output.language,
+        fields,
+        languages,
+    };
+    format::write(Path::new(&output.output), output.format, kanjidic, &options)
+}
+
+fn filter(opts: FilterOpts) -> Result<(), KdcError> {
+    let mut kanjidic = read(&opts.source)?;
+    let filter = opts.filter.filter();
+    kanjidic
+        .characters
+        .retain(|character| filter.matches(character));
+    write(
+        kanjidic,
+        &opts.output,
+        &opts.projection.fields,
+        &opts.projection.languages,
+    )
+}
+
+fn lookup(opts: LookupOpts) -> Result<(), KdcError> {
+    let kanjidic = read(&opts.source)?;
+    let Projection { fields, languages } = &opts.projection;
+    let mut entries = vec![];
+    for literal in opts.literals.chars() {
+        match kanjidic
+            .characters
+            .iter()
+            .find(|character| character.literal == literal)
+        {
+            Some(character) if fields.is_empty() => entries.push(serde_json::to_value(character)),
+            Some(character) => entries.push(Ok(field::project(character, fields, languages))),
+            None => eprintln!("No entry for {}", literal),
+        }
+    }
+    let entries: Result<Vec<_>, _> = entries.into_iter().collect();
+    let json = entries.and_then(|entries| serde_json::to_string_pretty(&entries));
+    println!("{}", json.map_err(|err| KdcError::Json(err.into()))?);
+    Ok(())
+}
+
+fn deck(opts: DeckOpts) -> Result<(), KdcError> {
+    let front: Template = opts.front.parse()?;
+    let back: Template = opts.back.parse()?;
+    let kanjidic = read(&opts.source)?;
+    let filter = opts.filter.filter();
+    let options = deck::Options {
+        front: &front,
+        back: &back,
+        filter: &filter,
+        order: opts.order.as_deref(),
+        language: opts.language,
+        deck: opts.deck.as_deref(),
+    };
+    deck::write(Path::new(&opts.output), &kanjidic.characters, &options)
+}
+
+fn diff(opts: DiffOpts) -> Result<(), KdcError> {
+    let read_file = |input: &str| {
+        read(&Source {
+            input: input.to_owned(),
+            lenient: opts.lenient,
+        })
+    };
+    let old = read_file(&opts.old)?;
+    let new = read_file(&opts.new)?;
+    let diff = diff::diff(&old, &new);
+    if opts.json {
+        let json = serde_json::to_string_pretty(&diff).map_err(|err| KdcError::Json(err.into()))?;
+        println!("{}", json);
+    } else {
+        print!("{}", diff);
+    }
+    Ok(())
+}
+
+fn delta(opts: DeltaOpts) -> Result<(), KdcError> {
+    let read_file = |input: &str| {
+        read(&Source {
+            input: input.to_owned(),
+            lenient: opts.lenient,
+        })
+    };
+    let old = read_file(&opts.old)?;
+    let new = read_file(&opts.new)?;
+    let delta = Delta::new(
+        old.header.database_version,
+        &old.characters,
+        new.header.database_version,
+        &new.characters,
+    )?;
+    let mut out = BufWriter::new(File::create(&opts.output)?);
+    serde_json::to_writer(&mut out, &delta).map_err(|err| KdcError::Json(err.into()))?;
+    out.flush()?;
+    eprintln!(
+        "{} -> {}: {} added, {} removed, {} changed",
+        delta.from,
+        delta.to,
+        delta.added.len(),
+        delta.removed.len(),
+        delta.changed.len()
+    );
+    Ok(())
+}
+
+fn stats(opts: StatsOpts) -> Result<(), KdcError> {
+    let kanjidic = read(&opts.source)?;
+    let stats = Stats::new(&kanjidic);
+    if opts.json {
+        let json =
+            serde_json::to_string_pretty(&stats).map_err(|err| KdcError::Json(err.into()))?;
+        println!("{}", json);
     } else {
-        serde_json::to_string_pretty(&kanjidic.characters)
+        print!("{}", stats);
     }
-    .map_err(|err| KdcError::Json(err.into()))?;
-    fs::write(opts.output, json)?;
     Ok(())
 }
+
+fn read(source: &Source) -> Result<Kanjidic, KdcError> {
+    let xml = std::fs::read_to_string(&source.input)?;
+    let strictness = if source.lenient {
+        Strictness::Lenient
+    } else {
+        Strictness::Strict
+    };
+    let report = Kanjidic::parse(&xml, strictness)?;
+    for diagnostic in report.diagnostics.iter() {
+        let literal = diagnostic.literal.unwrap_or('?');
+        eprintln!(
+            "Skipped {} ({}): {}",
+            literal, diagnostic.position, diagnostic.error
+        );
+    }
+    Ok(report.kanjidic)
+}
@@ -0,0 +1,388 @@
+use crate::KdcError;
+use kanjidic_parser::{date_of_creation::DateOfCreation, kanjidic::Kanjidic};
+use kanjidic_types::{Character, QueryCode, Reading, Reference};
+use rusqlite::{params, Connection, Transaction};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Tables for each part of a character, keyed by the character's codepoint.
+/// Readings and meanings keep the index of the rmgroup they came from.
+const SCHEMA: &str = r#"
+CREATE TABLE header (
+    file_version INTEGER NOT NULL,
+    database_version TEXT NOT NULL,
+    date_of_creation TEXT NOT NULL
+);
+CREATE TABLE characters (
+    id INTEGER PRIMARY KEY,
+    literal TEXT NOT NULL UNIQUE,
+    grade INTEGER,
+    stroke_count INTEGER NOT NULL,
+    frequency INTEGER,
+    jlpt INTEGER
+);
+CREATE TABLE stroke_miscounts (
+    character_id INTEGER NOT NULL REFERENCES characters(id),
+    stroke_count INTEGER NOT NULL
+);
+CREATE TABLE codepoints (
+    character_id INTEGER NOT NULL REFERENCES characters(id),
+    kind TEXT NOT NULL,
+    value TEXT NOT NULL
+);
+CREATE TABLE radicals (
+    character_id INTEGER NOT NULL REFERENCES characters(id),
+    kind TEXT NOT NULL,
+    radical INTEGER NOT NULL
+);
+CREATE TABLE radical_names (
+    character_id INTEGER NOT NULL REFERENCES characters(id),
+    name TEXT NOT NULL
+);
+CREATE TABLE variants (
+    character_id INTEGER NOT NULL REFERENCES characters(id),
+    kind TEXT NOT NULL,
+    value TEXT NOT NULL
+);
+CREATE TABLE dictionary_references (
+    character_id INTEGER NOT NULL REFERENCES characters(id),
+    kind TEXT NOT NULL,
+    value TEXT NOT NULL,
+    volume INTEGER,
+    page INTEGER
+);
+CREATE TABLE query_codes (
+    character_id INTEGER NOT NULL REFERENCES characters(id),
+    kind TEXT NOT NULL,
+    value TEXT NOT NULL,
+    misclassification TEXT
+);
+CREATE TABLE readings (
+    character_id INTEGER NOT NULL REFERENCES characters(id),
+    rmgroup INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    value TEXT NOT NULL,
+    on_type TEXT,
+    jouyou INTEGER NOT NULL
+);
+CREATE TABLE meanings (
+    id INTEGER PRIMARY KEY,
+    character_id INTEGER NOT NULL REFERENCES characters(id),
+    rmgroup INTEGER NOT NULL,
+    language TEXT NOT NULL,
+    meaning TEXT NOT NULL
+);
+CREATE TABLE nanori (
+    character_id INTEGER NOT NULL REFERENCES characters(id),
+    nanori TEXT NOT NULL
+);
+CREATE TABLE decompositions (
+    character_id INTEGER NOT NULL REFERENCES characters(id),
+    radical TEXT NOT NULL
+);
+CREATE VIRTUAL TABLE meanings_fts USING fts5(
+    meaning,
+    content = 'meanings',
+    content_rowid = 'id'
+);
+"#;
+
+const INDEXES: &str = r#"
+CREATE INDEX characters_grade ON characters(grade);
+CREATE INDEX characters_stroke_count ON characters(stroke_count);
+CREATE INDEX characters_frequency ON characters(frequency);
+CREATE INDEX characters_jlpt ON characters(jlpt);
+CREATE INDEX stroke_miscounts_character ON stroke_miscounts(character_id);
+CREATE INDEX codepoints_character ON codepoints(character_id);
+CREATE INDEX codepoints_value ON codepoints(kind, value);
+CREATE INDEX radicals_character ON radicals(character_id);
+CREATE INDEX radicals_radical ON radicals(kind, radical);
+CREATE INDEX radical_names_character ON radical_names(character_id);
+CREATE INDEX variants_character ON variants(character_id);
+CREATE INDEX variants_value ON variants(kind, value);
+CREATE INDEX dictionary_references_character ON dictionary_references(character_id);
+CREATE INDEX dictionary_references_value ON dictionary_references(kind, value);
+CREATE INDEX query_codes_character ON query_codes(character_id);
+CREATE INDEX query_codes_value ON query_codes(kind, value);
+CREATE INDEX readings_character ON readings(character_id);
+CREATE INDEX readings_value ON readings(kind, value);
+CREATE INDEX meanings_character ON meanings(character_id);
+CREATE INDEX meanings_language ON meanings(language);
+CREATE INDEX nanori_character ON nanori(character_id);
+CREATE INDEX nanori_nanori ON nanori(nanori);
+CREATE INDEX decompositions_character ON decompositions(character_id);
+CREATE INDEX decompositions_radical ON decompositions(radical);
+INSERT INTO meanings_fts(meanings_fts) VALUES ('rebuild');
+"#;
+
+/// Writes the contents of the file to a new SQLite database at the given path,
+/// replacing any file that is already there. The database is built next to
+/// the path and only moved into place once it is complete, so a failed
+/// conversion leaves the previous database intact.
+pub fn write(path: &Path, kanjidic: &Kanjidic) -> Result<(), KdcError> {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    let temp = PathBuf::from(temp);
+    if temp.exists() {
+        fs::remove_file(&temp)?;
+    }
+    match write_new(&temp, kanjidic) {
+        Ok(()) => Ok(fs::rename(&temp, path)?),
+        Err(err) => {
+            let _ = fs::remove_file(&temp);
+            Err(err)
+        }
+    }
+}
+
+fn write_new(path: &Path, kanjidic: &Kanjidic) -> Result<(), KdcError> {
+    let mut connection = Connection::open(path)?;
+    populate(&mut connection, kanjidic)?;
+    connection.close().map_err(|(_, err)| err)?;
+    Ok(())
+}
+
+/// Creates the tables and indexes and fills them in.
+pub fn populate(connection: &mut Connection, kanjidic: &Kanjidic) -> Result<(), rusqlite::Error> {
+    let tx = connection.transaction()?;
+    tx.execute_batch(SCHEMA)?;
+    insert_header(&tx, kanjidic)?;
+    for character in kanjidic.characters.iter() {
+        insert_character(&tx, character)?;
+    }
+    tx.execute_batch(INDEXES)?;
+    tx.commit()
+}
+
+fn insert_header(tx: &Transaction, kanjidic: &Kanjidic) -> Result<(), rusqlite::Error> {
+    let header = &kanjidic.header;
+    let DateOfCreation {
+        year: y,
+        month,
+        day,
+    } = header.date_of_creation;
+    tx.execute(
+        "INSERT INTO header VALUES (?1, ?2, ?3)",
+        params![
+            header.file_version,
+            header.database_version.to_string(),
+            format!("{}-{:02}-{:02}", y, month, day),
+        ],
+    )?;
+    Ok(())
+}
+
+fn insert_character(tx: &Transaction, character: &Character) -> Result<(), rusqlite::Error> {
+    let id = character.literal as u32;
+    tx.prepare_cached("INSERT INTO characters VALUES (?1, ?2, ?3, ?4, ?5, ?6)")?
+        .execute(params![
+            id,
+            character.literal.to_string(),
+            character.grade.map(|grade| grade.level()),
+            character.stroke_counts.accepted,
+            character.frequency,
+            character.jlpt,
+        ])?;
+
+    let mut statement = tx.prepare_cached("INSERT INTO stroke_miscounts VALUES (?1, ?2)")?;
+    for miscount in character.stroke_counts.miscounts.iter() {
+        statement.execute(params![id, miscount])?;
+    }
+
+    let mut statement = tx.prepare_cached("INSERT INTO codepoints VALUES (?1, ?2, ?3)")?;
+    for codepoint in character.codepoints.iter() {
+        statement.execute(params![id, codepoint.kind(), codepoint.to_string()])?;
+    }
+
+    let mut statement = tx.prepare_cached("INSERT INTO radicals VALUES (?1, ?2, ?3)")?;
+    for radical in character.radicals.iter() {
+        statement.execute(params![id, radical.kind.to_string(), radical.radical as u8])?;
+    }
+
+    let mut statement = tx.prepare_cached("INSERT INTO radical_names VALUES (?1, ?2)")?;
+    for name in character.radical_names.iter() {
+        statement.execute(params![id, name])?;
+    }
+
+    let mut statement = tx.prepare_cached("INSERT INTO variants VALUES (?1, ?2, ?3)")?;
+    for variant in character.variants.iter() {
+        statement.execute(params![id, variant.kind(), variant.to_string()])?;
+    }
+
+    let mut statement =
+        tx.prepare_cached("INSERT INTO dictionary_references VALUES (?1, ?2, ?3, ?4, ?5)")?;
+    for reference in character.references.iter() {
+        let (volume, page) = match reference {
+            Reference::Moro(moro) => (moro.volume, moro.page),
+            _ => (None, None),
+        };
+        statement.execute(params![
+            id,
+            reference.kind(),
+            reference.to_string(),
+            volume,
+            page
+        ])?;
+    }
+
+    let mut statement = tx.prepare_cached("INSERT INTO query_codes VALUES (?1, ?2, ?3, ?4)")?;
+    for query_code in character.query_codes.iter() {
+        let misclassification = match query_code {
+            QueryCode::Misclassification(misclassification) => {
+                Some(misclassification.kind.to_string())
+            }
+            _ => None,
+        };
+        statement.execute(params![
+            id,
+            query_code.kind(),
+            query_code.to_string(),
+            misclassification
+        ])?;
+    }
+
+    let mut readings = tx.prepare_cached("INSERT INTO readings VALUES (?1, ?2, ?3, ?4, ?5, ?6)")?;
+    let mut meanings = tx.prepare_cached(
+        "INSERT INTO meanings (character_id, rmgroup, language, meaning) VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    for (group_index, group) in character.reading_meaning_groups.iter().enumerate() {
+        for reading in group.readings.iter() {
+            let (on_type, jouyou) = match reading {
+                Reading::Onyomi(onyomi) => (
+                    onyomi.kind.as_ref().map(|kind| kind.to_string()),
+                    onyomi.jouyou,
+                ),
+                Reading::Kunyomi(kunyomi) => (None, kunyomi.jouyou),
+                _ => (None, false),
+            };
+            readings.execute(params![
+                id,
+                group_index,
+                reading.kind(),
+                reading.to_string(),
+                on_type,
+                jouyou
+            ])?;
+        }
+        for (language, translations) in group.translations.iter() {
+            for translation in translations.iter() {
+                meanings.execute(params![id, group_index, language.code(), translation])?;
+            }
+        }
+    }
+
+    let mut statement = tx.prepare_cached("INSERT INTO nanori VALUES (?1, ?2)")?;
+    for nanori in character.nanori.iter() {
+        statement.execute(params![id, nanori])?;
+    }
+
+    let mut statement = tx.prepare_cached("INSERT INTO decompositions VALUES (?1, ?2)")?;
+    for radical in character.decomposition.iter() {
+        statement.execute(params![id, radical.to_string()])?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::populate;
+    use kanjidic_parser::kanjidic::{Kanjidic, Strictness};
+    use rusqlite::Connection;
+
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<kanjidic2>
+<header>
+<file_version>4</file_version>
+<database_version>2021-176</database_version>
+<date_of_creation>2021-06-25</date_of_creation>
+</header>
+<character>
+<literal>亜</literal>
+<codepoint>
+<cp_value cp_type="ucs">4e9c</cp_value>
+</codepoint>
+<radical>
+<rad_value rad_type="classical">7</rad_value>
+</radical>
+<misc>
+<grade>8</grade>
+<stroke_count>7</stroke_count>
+<freq>1509</freq>
+</misc>
+<dic_number>
+<dic_ref dr_type="moro" m_vol="1" m_page="0525">272</dic_ref>
+</dic_number>
+<query_code>
+<q_code qc_type="skip">4-7-1</q_code>
+<q_code qc_type="skip" skip_misclass="posn">1-2-5</q_code>
+</query_code>
+<reading_meaning>
+<rmgroup>
+<reading r_type="ja_on" on_type="kan" r_status="jy">ア</reading>
+<reading r_type="ja_kun">つ.ぐ</reading>
+<meaning>Asia</meaning>
+<meaning>rank next</meaning>
+<meaning m_lang="fr">Asie</meaning>
+</rmgroup>
+<nanori>や</nanori>
+</reading_meaning>
+</character>
+</kanjidic2>
+"#;
+
+    #[test]
+    fn populates_tables() {
+        let kanjidic: Kanjidic = Kanjidic::parse(XML, Strictness::Strict).unwrap().kanjidic;
+        let mut connection = Connection::open_in_memory().unwrap();
+        populate(&mut connection, &kanjidic).unwrap();
+
+        let (literal, grade): (String, u8) = connection
+            .query_row("SELECT literal, grade FROM characters", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert_eq!((literal.as_str(), grade), ("亜", 8));
+
+        let onyomi: (String, String, bool) = connection
+            .query_row(
+                "SELECT value, on_type, jouyou FROM readings WHERE kind = 'ja_on'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(onyomi, ("ア".into(), "kan".into(), true));
+
+        let moro: (String, u8, u16) = connection
+            .query_row(
+                "SELECT value, volume, page FROM dictionary_references WHERE kind = 'moro'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(moro, ("272".into(), 1, 525));
+
+        let misclassification: String = connection
+            .query_row(
+                "SELECT misclassification FROM query_codes WHERE value = '1-2-5'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(misclassification, "posn");
+
+        let found: String = connection
+            .query_row(
+                "SELECT characters.literal FROM meanings_fts
+                JOIN meanings ON meanings.id = meanings_fts.rowid
+                JOIN characters ON characters.id = meanings.character_id
+                WHERE meanings_fts MATCH 'rank'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(found, "亜");
+    }
+}
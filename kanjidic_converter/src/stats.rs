@@ -0,0 +1,210 @@
+use kanjidic_parser::{database_version::DatabaseVersion, kanjidic::Kanjidic};
+use kanjidic_types::{radical::RadicalKind, KangXi, Language};
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+/// A summary of the contents of a KANJIDIC2 file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Stats {
+    /// The version of the file.
+    pub version: DatabaseVersion,
+    /// The number of characters in the file.
+    pub characters: usize,
+    /// The number of characters at each grade number.
+    pub grades: BTreeMap<u8, usize>,
+    /// The number of characters without a grade.
+    pub ungraded: usize,
+    /// The number of characters at each pre-2010 JLPT level.
+    pub jlpt: BTreeMap<u8, usize>,
+    /// The number of characters with each accepted stroke count.
+    pub stroke_counts: BTreeMap<u8, usize>,
+    /// The number of characters with a reference of each dr_type.
+    pub references: BTreeMap<String, usize>,
+    /// The number of characters with a query code of each qc_type.
+    pub query_codes: BTreeMap<String, usize>,
+    /// The number of meanings in each language.
+    pub meanings: BTreeMap<Language, usize>,
+    /// Characters without meanings in any language.
+    pub untranslated: Vec<char>,
+    /// The number of characters classified under each classical radical.
+    pub radicals: BTreeMap<KangXi, usize>,
+}
+
+impl Stats {
+    /// Tallies the characters in the file.
+    pub fn new(kanjidic: &Kanjidic) -> Self {
+        let mut out = Self {
+            version: kanjidic.header.database_version,
+            characters: kanjidic.characters.len(),
+            grades: BTreeMap::new(),
+            ungraded: 0,
+            jlpt: BTreeMap::new(),
+            stroke_counts: BTreeMap::new(),
+            references: BTreeMap::new(),
+            query_codes: BTreeMap::new(),
+            meanings: BTreeMap::new(),
+            untranslated: vec![],
+            radicals: BTreeMap::new(),
+        };
+        for character in kanjidic.characters.iter() {
+            match character.grade {
+                Some(grade) => *out.grades.entry(grade.level()).or_default() += 1,
+                None => out.ungraded += 1,
+            }
+            if let Some(jlpt) = character.jlpt {
+                *out.jlpt.entry(jlpt).or_default() += 1;
+            }
+            *out.stroke_counts
+                .entry(character.stroke_counts.accepted)
+                .or_default() += 1;
+            let kinds: BTreeSet<_> = character
+                .references
+                .iter()
+                .map(|reference| reference.kind())
+                .collect();
+            for kind in kinds {
+                *out.references.entry(kind.to_owned()).or_default() += 1;
+            }
+            let kinds: BTreeSet<_> = character
+                .query_codes
+                .iter()
+                .map(|query_code| query_code.kind())
+                .collect();
+            for kind in kinds {
+                *out.query_codes.entry(kind.to_owned()).or_default() += 1;
+            }
+            let translations = character.translations();
+            if translations.values().all(Vec::is_empty) {
+                out.untranslated.push(character.literal);
+            }
+            for (language, meanings) in translations {
+                *out.meanings.entry(language).or_default() += meanings.len();
+            }
+            for radical in character.radicals.iter() {
+                if radical.kind == RadicalKind::Classical {
+                    *out.radicals.entry(radical.radical).or_default() += 1;
+                }
+            }
+        }
+        out
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Database version {}", self.version)?;
+        writeln!(f, "Characters: {}", self.characters)?;
+        let mut grades = labeled(&self.grades);
+        grades.push(("none".to_owned(), self.ungraded));
+        table(f, "Grade", &grades)?;
+        table(f, "JLPT", &labeled(&self.jlpt))?;
+        table(f, "Strokes", &labeled(&self.stroke_counts))?;
+        table(f, "Reference", &labeled(&self.references))?;
+        table(f, "Query code", &labeled(&self.query_codes))?;
+        table(f, "Language", &labeled(&self.meanings))?;
+        let radicals: Vec<_> = self
+            .radicals
+            .iter()
+            .map(|(radical, count)| (format!("{} {:?}", *radical as u8, radical), *count))
+            .collect();
+        table(f, "Radical", &radicals)?;
+        writeln!(f)?;
+        writeln!(f, "Untranslated: {}", self.untranslated.len())?;
+        let untranslated: String = self.untranslated.iter().collect();
+        if !untranslated.is_empty() {
+            writeln!(f, "{}", untranslated)?;
+        }
+        Ok(())
+    }
+}
+
+fn labeled<K: ToString>(counts: &BTreeMap<K, usize>) -> Vec<(String, usize)> {
+    counts
+        .iter()
+        .map(|(key, count)| (key.to_string(), *count))
+        .collect()
+}
+
+/// Writes a heading and a row for each label,
+/// with the counts lined up in a column.
+fn table(f: &mut fmt::Formatter, heading: &str, rows: &[(String, usize)]) -> fmt::Result {
+    let width = rows
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .chain(std::iter::once(heading.chars().count()))
+        .max()
+        .unwrap_or_default();
+    writeln!(f)?;
+    writeln!(f, "{:width$}  Count", heading, width = width)?;
+    for (label, count) in rows {
+        let padding = width - label.chars().count();
+        writeln!(f, "{}{}  {:>5}", label, " ".repeat(padding), count)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stats;
+    use kanjidic_parser::kanjidic::{Kanjidic, Strictness};
+    use kanjidic_types::{KangXi, Language};
+
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<kanjidic2>
+<header>
+<file_version>4</file_version>
+<database_version>2021-176</database_version>
+<date_of_creation>2021-06-25</date_of_creation>
+</header>
+<character>
+<literal>亜</literal>
+<codepoint><cp_value cp_type="ucs">4e9c</cp_value></codepoint>
+<radical><rad_value rad_type="classical">7</rad_value></radical>
+<misc><grade>8</grade><stroke_count>7</stroke_count><jlpt>1</jlpt></misc>
+<dic_number>
+<dic_ref dr_type="heisig6">1809</dic_ref>
+<dic_ref dr_type="moro" m_vol="1" m_page="0525">272</dic_ref>
+</dic_number>
+<query_code>
+<q_code qc_type="skip">4-7-1</q_code>
+<q_code qc_type="skip" skip_misclass="posn">1-2-5</q_code>
+</query_code>
+<reading_meaning>
+<rmgroup>
+<meaning>Asia</meaning>
+<meaning>rank next</meaning>
+<meaning m_lang="fr">Asie</meaning>
+</rmgroup>
+</reading_meaning>
+</character>
+<character>
+<literal>唖</literal>
+<codepoint><cp_value cp_type="ucs">5516</cp_value></codepoint>
+<radical><rad_value rad_type="classical">30</rad_value></radical>
+<misc><stroke_count>10</stroke_count></misc>
+</character>
+</kanjidic2>
+"#;
+
+    #[test]
+    fn counts() {
+        let kanjidic = Kanjidic::parse(XML, Strictness::Strict).unwrap().kanjidic;
+        let stats = Stats::new(&kanjidic);
+        assert_eq!(stats.characters, 2);
+        assert_eq!(stats.grades.get(&8), Some(&1));
+        assert_eq!(stats.ungraded, 1);
+        assert_eq!(stats.jlpt.get(&1), Some(&1));
+        assert_eq!(stats.stroke_counts.get(&10), Some(&1));
+        assert_eq!(stats.references.get("heisig6"), Some(&1));
+        assert_eq!(stats.references.get("moro"), Some(&1));
+        assert_eq!(stats.query_codes.get("skip"), Some(&1));
+        assert_eq!(stats.meanings.get(&Language::En), Some(&2));
+        assert_eq!(stats.meanings.get(&Language::Fr), Some(&1));
+        assert_eq!(stats.untranslated, vec!['唖']);
+        assert_eq!(stats.radicals.get(&KangXi::Mouth), Some(&1));
+    }
+}
@@ -1,16 +1,16 @@
 use crate::{
     codepoint, grade,
-    pos_error::PosError,
+    pos_error::{Offset, PosError},
     query_code, radical, reading, reference,
     shared::{children, text, text_uint, SharedError},
     stroke_count::{self, StrokeCountBuilder},
     translation, variant,
 };
 use kanjidic_types::{
-    character::Translations, Character, Codepoint, Grade, QueryCode, Radical, Reading, Reference,
-    StrokeCount, Variant,
+    character::{ReadingMeaningGroup, Translations},
+    Character, Codepoint, Grade, QueryCode, Radical, Reference, StrokeCount, Variant,
 };
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
 pub enum CharacterError {
@@ -42,6 +42,25 @@ pub enum CharacterError {
     IncompleteCharacter,
 }
 
+impl Offset for CharacterError {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            CharacterError::Shared(err) => err.offset(origin),
+            CharacterError::Codepoint(err) => err.offset(origin),
+            CharacterError::Radical(err) => err.offset(origin),
+            CharacterError::Grade(err) => err.offset(origin),
+            CharacterError::StrokeCount(err) => err.offset(origin),
+            CharacterError::Variant(err) => err.offset(origin),
+            CharacterError::Translation(err) => err.offset(origin),
+            CharacterError::Reading(err) => err.offset(origin),
+            CharacterError::QueryCode(err) => err.offset(origin),
+            CharacterError::DictionaryReference(err) => err.offset(origin),
+            CharacterError::NanoriText(pos) => pos.offset(origin),
+            CharacterError::NonCharString | CharacterError::IncompleteCharacter => {}
+        }
+    }
+}
+
 struct CharacterBuilder {
     /// The character itself.
     pub literal: Option<char>,
@@ -66,10 +85,8 @@ struct CharacterBuilder {
     pub references: Option<Vec<Reference>>,
     /// Codes used to identify the kanji
     pub query_codes: Option<Vec<QueryCode>>,
-    /// Different ways the kanji can be read.
-    pub readings: Option<Vec<Reading>>,
-    /// Translations of the kanji into different languages.
-    pub translations: Option<Translations>,
+    /// Groups of readings along with the meanings associated with them.
+    pub reading_meaning_groups: Option<Vec<ReadingMeaningGroup>>,
     /// Japanese readings associated with names.
     pub nanori: Option<Vec<String>>,
     /// The constituent radicals in the kanji
@@ -90,8 +107,7 @@ impl CharacterBuilder {
             jlpt: None,
             references: None,
             query_codes: None,
-            readings: None,
-            translations: None,
+            reading_meaning_groups: None,
             nanori: None,
             decomposition: None,
         }
@@ -111,8 +127,7 @@ impl CharacterBuilder {
         let jlpt = self.jlpt;
         let references = self.references.unwrap_or_default();
         let query_codes = self.query_codes.unwrap_or_default();
-        let readings = self.readings.unwrap_or_default();
-        let translations = self.translations.unwrap_or_default();
+        let reading_meaning_groups = self.reading_meaning_groups.unwrap_or_default();
         let nanori = self.nanori.unwrap_or_default();
         let decomposition = self.decomposition.unwrap_or_default();
 
@@ -128,8 +143,7 @@ impl CharacterBuilder {
             jlpt,
             references,
             query_codes,
-            readings,
-            translations,
+            reading_meaning_groups,
             nanori,
             decomposition,
         })
@@ -182,11 +196,12 @@ fn unpack_reading_meaning(
     reading_meaning: &Node,
     builder: &mut CharacterBuilder,
 ) -> Result<(), CharacterError> {
+    let mut groups = vec![];
     let mut nanori = vec![];
     for child in reading_meaning.children() {
         match child.tag_name().name() {
             "rmgroup" => {
-                unpack_rmgroup(&child, builder)?;
+                groups.push(rmgroup(&child)?);
             }
             "nanori" => {
                 nanori.push(
@@ -198,11 +213,12 @@ fn unpack_reading_meaning(
             _ => {}
         }
     }
+    builder.reading_meaning_groups = Some(groups);
     builder.nanori = Some(nanori);
     Ok(())
 }
 
-fn unpack_rmgroup(rmgroup: &Node, builder: &mut CharacterBuilder) -> Result<(), CharacterError> {
+fn rmgroup(rmgroup: &Node) -> Result<ReadingMeaningGroup, CharacterError> {
     let mut readings = vec![];
     let mut translations = Translations::default();
     for child in rmgroup.children() {
@@ -216,9 +232,10 @@ fn unpack_rmgroup(rmgroup: &Node, builder: &mut CharacterBuilder) -> Result<(),
             _ => {}
         }
     }
-    builder.readings = Some(readings);
-    builder.translations = Some(translations);
-    Ok(())
+    Ok(ReadingMeaningGroup {
+        readings,
+        translations,
+    })
 }
 
 fn unpack_misc(misc: &Node, builder: &mut CharacterBuilder) -> Result<(), CharacterError> {
@@ -267,6 +284,7 @@ mod tests {
     use super::from;
     use crate::test_shared::DOC;
     use kanjidic_types::{
+        character::ReadingMeaningGroup,
         de_roo::{ExtremeBottom, ExtremeTop},
         four_corner::Stroke,
         kunyomi::KunyomiKind,
@@ -275,11 +293,12 @@ mod tests {
         pin_yin::Tone,
         radical::RadicalKind,
         skip::{SkipSolid, SolidSubpattern},
-        Character, Codepoint, DeRoo, FourCorner, Grade, KangXi, Kunyomi, Kuten, Moro, Oneill,
-        PinYin, QueryCode, Radical, Reading, Reference, ShDesc, ShRadical, Skip, StrokeCount,
-        Variant,
+        Character, Codepoint, DeRoo, FourCorner, Grade, KangXi, Kunyomi, Kuten, Language, Moro,
+        Oneill, Onyomi, PinYin, QueryCode, Radical, Reading, Reference, ShDesc, ShRadical, Skip,
+        StrokeCount, Variant,
     };
-    use std::{collections::HashMap, iter::FromIterator};
+    use roxmltree::Document;
+    use std::{collections::BTreeMap, iter::FromIterator};
 
     #[test]
     fn character() {
@@ -378,60 +397,100 @@ mod tests {
                 ],
                 radical_names: vec![],
                 nanori: vec!["や".into(), "つぎ".into(), "つぐ".into(),],
-                readings: vec![
-                    Reading::PinYin(PinYin {
-                        romanization: "ya".into(),
-                        tone: Tone::Falling,
-                    }),
-                    Reading::KoreanRomanized("a".into()),
-                    Reading::KoreanHangul("아".into()),
-                    Reading::Vietnam("A".into()),
-                    Reading::Vietnam("Á".into()),
-                    Reading::Onyomi("ア".into()),
-                    Reading::Kunyomi(Kunyomi {
-                        kind: KunyomiKind::Normal,
-                        reading: "つ".into(),
-                        okurigana: Some("ぐ".into()),
-                    })
-                ],
-                translations: HashMap::from_iter([
-                    (
-                        "en".to_owned(),
-                        vec![
-                            "Asia".to_owned(),
-                            "rank next".to_owned(),
-                            "come after".to_owned(),
-                            "-ous".to_owned(),
-                        ]
-                    ),
-                    (
-                        "fr".to_owned(),
-                        vec![
-                            "Asie".to_owned(),
-                            "suivant".to_owned(),
-                            "sub-".to_owned(),
-                            "sous-".to_owned(),
-                        ]
-                    ),
-                    (
-                        "pt".to_owned(),
-                        vec![
-                            "Ásia".to_owned(),
-                            "próxima".to_owned(),
-                            "o que vem depois".to_owned(),
-                            "-ous".to_owned(),
-                        ]
-                    ),
-                    (
-                        "es".to_owned(),
-                        vec![
-                            "pref. para indicar".to_owned(),
-                            "venir después de".to_owned(),
-                            "Asia".to_owned(),
-                        ]
-                    )
-                ])
+                reading_meaning_groups: vec![ReadingMeaningGroup {
+                    readings: vec![
+                        Reading::PinYin(PinYin {
+                            romanization: "ya".into(),
+                            tone: Tone::Falling,
+                        }),
+                        Reading::KoreanRomanized("a".into()),
+                        Reading::KoreanHangul("아".into()),
+                        Reading::Vietnam("A".into()),
+                        Reading::Vietnam("Á".into()),
+                        Reading::Onyomi(Onyomi {
+                            reading: "ア".into(),
+                            kind: None,
+                            jouyou: false,
+                        }),
+                        Reading::Kunyomi(Kunyomi {
+                            kind: KunyomiKind::Normal,
+                            reading: "つ".into(),
+                            okurigana: Some("ぐ".into()),
+                            jouyou: false,
+                        })
+                    ],
+                    translations: BTreeMap::from_iter([
+                        (
+                            Language::En,
+                            vec![
+                                "Asia".to_owned(),
+                                "rank next".to_owned(),
+                                "come after".to_owned(),
+                                "-ous".to_owned(),
+                            ]
+                        ),
+                        (
+                            Language::Fr,
+                            vec![
+                                "Asie".to_owned(),
+                                "suivant".to_owned(),
+                                "sub-".to_owned(),
+                                "sous-".to_owned(),
+                            ]
+                        ),
+                        (
+                            Language::Pt,
+                            vec![
+                                "Ásia".to_owned(),
+                                "próxima".to_owned(),
+                                "o que vem depois".to_owned(),
+                                "-ous".to_owned(),
+                            ]
+                        ),
+                        (
+                            Language::Es,
+                            vec![
+                                "pref. para indicar".to_owned(),
+                                "venir después de".to_owned(),
+                                "Asia".to_owned(),
+                            ]
+                        )
+                    ])
+                }],
             })
         )
     }
+
+    #[test]
+    fn multiple_rmgroups() {
+        let xml = r#"<character>
+<literal>亜</literal>
+<misc><stroke_count>7</stroke_count></misc>
+<reading_meaning>
+<rmgroup>
+<reading r_type="ja_on">ア</reading>
+<meaning>Asia</meaning>
+</rmgroup>
+<rmgroup>
+<reading r_type="ja_kun">つ.ぐ</reading>
+<meaning>rank next</meaning>
+<meaning m_lang="fr">suivant</meaning>
+</rmgroup>
+</reading_meaning>
+</character>"#;
+        let doc = Document::parse(xml).unwrap();
+        let character = from(doc.root_element()).unwrap();
+        assert_eq!(character.reading_meaning_groups.len(), 2);
+        assert_eq!(character.readings().count(), 2);
+        assert_eq!(
+            character.translations(),
+            BTreeMap::from_iter([
+                (
+                    Language::En,
+                    vec!["Asia".to_owned(), "rank next".to_owned()]
+                ),
+                (Language::Fr, vec!["suivant".to_owned()]),
+            ])
+        );
+    }
 }
@@ -1,6 +1,9 @@
-use crate::shared::{self, attr, text_hex, SharedError};
+use crate::{
+    pos_error::Offset,
+    shared::{self, attr, text_hex, SharedError},
+};
 use kanjidic_types::{kuten, Codepoint, Kuten};
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 use std::convert::TryFrom;
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
@@ -13,6 +16,15 @@ pub enum Error {
     Kuten(#[from] kuten::ParseError),
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Encoding | Error::Kuten(_) => {}
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Codepoint, Error> {
     let text = shared::text(&node)?;
     let encoding = attr(&node, "cp_type")?;
@@ -1,8 +1,10 @@
-use crate::shared::{self, SharedError};
-use kanjidic_types::{take_uint, IResult, NomErr, NomErrorReason};
-use nom::{character::complete::char, combinator::map_res, sequence::tuple};
-use roxmltree::Node;
-use serde::{Deserialize, Serialize};
+use crate::{
+    pos_error::{Offset, PosError},
+    shared::{self, SharedError},
+};
+pub use kanjidic_types::DatabaseVersion;
+use kanjidic_types::{database_version::ParseError, NomErrorReason};
+use roxmltree::{Node, TextPos};
 use std::convert::TryFrom;
 
 /// Error while parsing the database version
@@ -14,44 +16,29 @@ pub enum Error {
     Format(NomErrorReason),
 }
 
-/// The version of the file.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
-pub struct DatabaseVersion {
-    /// The year of release.
-    pub year: u16,
-    /// The version that came out in the given year,
-    /// with the counter being reset annually.
-    pub version: u16,
-}
-
-impl<'a> From<NomErr<'a>> for Error {
-    fn from(err: NomErr<'a>) -> Self {
-        Self::Format(err.into())
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Format(_) => {}
+        }
     }
 }
 
-impl<'a, 'input> TryFrom<Node<'a, 'input>> for DatabaseVersion {
-    type Error = Error;
-
-    fn try_from(node: Node) -> Result<DatabaseVersion, Error> {
-        let text = shared::text(&node)?;
-        Ok(map_res(take_db_version, map_db_version)(text).map(|(_, s)| s)?)
-    }
-}
-
-type DbVersionParts<'a> = (u16, char, u16);
-
-fn take_db_version(s: &str) -> IResult<DbVersionParts> {
-    tuple((take_uint, char('-'), take_uint))(s)
-}
-
-fn map_db_version(parts: DbVersionParts) -> Result<DatabaseVersion, Error> {
-    let (year, _, version) = parts;
-    Ok(DatabaseVersion { year, version })
+/// Reads the version from a database_version element,
+/// with the position of the element in errors.
+/// `DatabaseVersion::try_from(node)` reads it without the position.
+pub fn from(node: Node) -> Result<DatabaseVersion, Error> {
+    let text = shared::text(&node)?;
+    DatabaseVersion::try_from(text).map_err(|err| match err {
+        ParseError::Format(reason) => Error::Format(reason),
+        ParseError::NoText => SharedError::NoText(PosError::from(&node)).into(),
+    })
 }
 
 #[cfg(test)]
 mod tests {
+    use super::from;
     use crate::{database_version::DatabaseVersion, test_shared::DOC};
     use std::convert::TryFrom;
 
@@ -61,13 +48,11 @@ mod tests {
             .descendants()
             .find(|node| node.has_tag_name("database_version"))
             .unwrap();
-        let version = DatabaseVersion::try_from(node);
-        assert_eq!(
-            version,
-            Ok(DatabaseVersion {
-                year: 2021,
-                version: 176,
-            })
-        )
+        let expected = DatabaseVersion {
+            year: 2021,
+            version: 176,
+        };
+        assert_eq!(from(node), Ok(expected));
+        assert_eq!(DatabaseVersion::try_from(node), Ok(expected));
     }
 }
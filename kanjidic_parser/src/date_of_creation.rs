@@ -1,7 +1,10 @@
-use crate::shared::{self, SharedError};
+use crate::{
+    pos_error::Offset,
+    shared::{self, SharedError},
+};
 use kanjidic_types::{take_uint, IResult, NomErr, NomErrorReason};
 use nom::{character::complete::char, combinator::map_res, sequence::tuple};
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -25,6 +28,15 @@ pub enum Error {
     Format(NomErrorReason),
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Format(_) => {}
+        }
+    }
+}
+
 impl<'a> From<NomErr<'a>> for Error {
     fn from(err: NomErr<'a>) -> Self {
         Self::Format(err.into())
@@ -1,9 +1,9 @@
 use crate::{
-    pos_error::PosError,
+    pos_error::{Offset, PosError},
     shared::{text, SharedError},
 };
 use kanjidic_types::{de_roo, DeRoo};
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 use std::convert::TryFrom;
 
 #[derive(Debug, thiserror::Error, Eq, PartialEq, Clone)]
@@ -14,6 +14,15 @@ pub enum Error {
     Str(PosError, de_roo::ParseError),
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Str(pos, _) => pos.offset(origin),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<DeRoo, Error> {
     DeRoo::try_from(text(&node)?).map_err(|err| Error::Str(PosError::from(&node), err))
 }
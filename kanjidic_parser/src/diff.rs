@@ -0,0 +1,405 @@
+use crate::{database_version::DatabaseVersion, kanjidic::Kanjidic};
+use kanjidic_types::{
+    Character, Codepoint, Comparison, Grade, Language, QueryCode, Radical, Reading, Reference,
+    StrokeCount, Variant,
+};
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+/// The differences between two KANJIDIC2 releases.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diff {
+    /// The version of the older file.
+    pub from: DatabaseVersion,
+    /// The version of the newer file.
+    pub to: DatabaseVersion,
+    /// Characters only in the newer file.
+    pub added: Vec<char>,
+    /// Characters only in the older file.
+    pub removed: Vec<char>,
+    /// Changes to characters in both files, keyed by literal.
+    pub changed: BTreeMap<char, Vec<Change>>,
+}
+
+/// Entries of a list that were added or removed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Delta<T> {
+    /// Entries only in the newer file.
+    pub added: Vec<T>,
+    /// Entries only in the older file.
+    pub removed: Vec<T>,
+}
+
+/// A change to one field of a character.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "field", rename_all = "camelCase")]
+pub enum Change {
+    Codepoints(Delta<Codepoint>),
+    Radicals(Delta<Radical>),
+    Grade {
+        old: Option<Grade>,
+        new: Option<Grade>,
+    },
+    StrokeCounts {
+        old: StrokeCount,
+        new: StrokeCount,
+    },
+    Variants(Delta<Variant>),
+    Frequency {
+        old: Option<u16>,
+        new: Option<u16>,
+    },
+    RadicalNames(Delta<String>),
+    Jlpt {
+        old: Option<u8>,
+        new: Option<u8>,
+    },
+    References(Delta<Reference>),
+    QueryCodes(Delta<QueryCode>),
+    Readings(Delta<Reading>),
+    /// Meanings in one language, merged across reading and meaning groups
+    Meanings {
+        language: Language,
+        added: Vec<String>,
+        removed: Vec<String>,
+    },
+    Nanori(Delta<String>),
+    Decomposition(Delta<char>),
+}
+
+/// Compares two parsed files, matching characters by literal.
+pub fn diff(old: &Kanjidic, new: &Kanjidic) -> Diff {
+    let comparison = Comparison::new(&old.characters, &new.characters);
+    Diff {
+        from: old.header.database_version,
+        to: new.header.database_version,
+        added: comparison
+            .added
+            .iter()
+            .map(|character| character.literal)
+            .collect(),
+        removed: comparison
+            .removed
+            .iter()
+            .map(|character| character.literal)
+            .collect(),
+        changed: comparison
+            .common
+            .iter()
+            .map(|(old, new)| (new.literal, character_changes(old, new)))
+            .filter(|(_, changes)| !changes.is_empty())
+            .collect(),
+    }
+}
+
+/// The changes to the fields of a character between two releases.
+pub fn character_changes(old: &Character, new: &Character) -> Vec<Change> {
+    let mut out = vec![];
+    if let Some(delta) = delta(&old.codepoints, &new.codepoints) {
+        out.push(Change::Codepoints(delta));
+    }
+    if let Some(delta) = delta(&old.radicals, &new.radicals) {
+        out.push(Change::Radicals(delta));
+    }
+    if old.grade != new.grade {
+        out.push(Change::Grade {
+            old: old.grade,
+            new: new.grade,
+        });
+    }
+    if old.stroke_counts != new.stroke_counts {
+        out.push(Change::StrokeCounts {
+            old: old.stroke_counts.clone(),
+            new: new.stroke_counts.clone(),
+        });
+    }
+    if let Some(delta) = delta(&old.variants, &new.variants) {
+        out.push(Change::Variants(delta));
+    }
+    if old.frequency != new.frequency {
+        out.push(Change::Frequency {
+            old: old.frequency,
+            new: new.frequency,
+        });
+    }
+    if let Some(delta) = delta(&old.radical_names, &new.radical_names) {
+        out.push(Change::RadicalNames(delta));
+    }
+    if old.jlpt != new.jlpt {
+        out.push(Change::Jlpt {
+            old: old.jlpt,
+            new: new.jlpt,
+        });
+    }
+    if let Some(delta) = delta(&old.references, &new.references) {
+        out.push(Change::References(delta));
+    }
+    if let Some(delta) = delta(&old.query_codes, &new.query_codes) {
+        out.push(Change::QueryCodes(delta));
+    }
+    let old_readings: Vec<_> = old.readings().cloned().collect();
+    let new_readings: Vec<_> = new.readings().cloned().collect();
+    if let Some(delta) = delta(&old_readings, &new_readings) {
+        out.push(Change::Readings(delta));
+    }
+    let old_translations = old.translations();
+    let new_translations = new.translations();
+    let languages: BTreeSet<_> = old_translations
+        .keys()
+        .chain(new_translations.keys())
+        .collect();
+    for language in languages {
+        let old_meanings = old_translations
+            .get(language)
+            .map_or(&[][..], Vec::as_slice);
+        let new_meanings = new_translations
+            .get(language)
+            .map_or(&[][..], Vec::as_slice);
+        if let Some(Delta { added, removed }) = delta(old_meanings, new_meanings) {
+            out.push(Change::Meanings {
+                language: *language,
+                added,
+                removed,
+            });
+        }
+    }
+    if let Some(delta) = delta(&old.nanori, &new.nanori) {
+        out.push(Change::Nanori(delta));
+    }
+    if let Some(delta) = delta(&old.decomposition, &new.decomposition) {
+        out.push(Change::Decomposition(delta));
+    }
+    out
+}
+
+fn delta<T: PartialEq + Clone>(old: &[T], new: &[T]) -> Option<Delta<T>> {
+    let added: Vec<_> = new
+        .iter()
+        .filter(|item| !old.contains(item))
+        .cloned()
+        .collect();
+    let removed: Vec<_> = old
+        .iter()
+        .filter(|item| !new.contains(item))
+        .cloned()
+        .collect();
+    if added.is_empty() && removed.is_empty() {
+        None
+    } else {
+        Some(Delta { added, removed })
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} -> {}", self.from, self.to)?;
+        writeln!(f, "Added {}: {}", self.added.len(), literals(&self.added))?;
+        writeln!(
+            f,
+            "Removed {}: {}",
+            self.removed.len(),
+            literals(&self.removed)
+        )?;
+        writeln!(f, "Changed {}", self.changed.len())?;
+        for (literal, changes) in self.changed.iter() {
+            writeln!(f, "{}", literal)?;
+            for change in changes {
+                write!(f, "{}", change)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn literals(literals: &[char]) -> String {
+    literals
+        .iter()
+        .map(char::to_string)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Writes one indented line for each altered value or list entry.
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Codepoints(delta) => write_delta(f, "codepoint", delta),
+            Change::Radicals(delta) => write_delta(f, "radical", delta),
+            Change::Grade { old, new } => write_value(
+                f,
+                "grade",
+                old.map(|grade| grade.level()),
+                new.map(|grade| grade.level()),
+            ),
+            Change::StrokeCounts { old, new } => {
+                write_value(f, "stroke count", Some(old.accepted), Some(new.accepted))?;
+                let miscounts = delta(&old.miscounts, &new.miscounts);
+                match miscounts {
+                    Some(delta) => write_delta(f, "miscount", &delta),
+                    None => Ok(()),
+                }
+            }
+            Change::Variants(delta) => write_delta(f, "variant", delta),
+            Change::Frequency { old, new } => write_value(f, "frequency", *old, *new),
+            Change::RadicalNames(delta) => write_delta(f, "radical name", delta),
+            Change::Jlpt { old, new } => write_value(f, "jlpt", *old, *new),
+            Change::References(delta) => write_delta(f, "reference", delta),
+            Change::QueryCodes(delta) => write_delta(f, "query code", delta),
+            Change::Readings(delta) => write_delta(f, "reading", delta),
+            Change::Meanings {
+                language,
+                added,
+                removed,
+            } => {
+                for meaning in added {
+                    writeln!(f, "  + meaning {}: {}", language, meaning)?;
+                }
+                for meaning in removed {
+                    writeln!(f, "  - meaning {}: {}", language, meaning)?;
+                }
+                Ok(())
+            }
+            Change::Nanori(delta) => write_delta(f, "nanori", delta),
+            Change::Decomposition(delta) => write_delta(f, "component", delta),
+        }
+    }
+}
+
+fn write_value<T: fmt::Display>(
+    f: &mut fmt::Formatter,
+    name: &str,
+    old: Option<T>,
+    new: Option<T>,
+) -> fmt::Result {
+    let show = |value: Option<T>| value.map_or_else(|| "none".to_owned(), |v| v.to_string());
+    writeln!(f, "  {}: {} -> {}", name, show(old), show(new))
+}
+
+fn write_delta<T: Describe>(f: &mut fmt::Formatter, name: &str, delta: &Delta<T>) -> fmt::Result {
+    for item in delta.added.iter() {
+        writeln!(f, "  + {} {}", name, item.describe())?;
+    }
+    for item in delta.removed.iter() {
+        writeln!(f, "  - {} {}", name, item.describe())?;
+    }
+    Ok(())
+}
+
+/// Text for a list entry in the human-readable diff,
+/// with coded values labeled by their KANJIDIC2 type.
+trait Describe {
+    fn describe(&self) -> String;
+}
+
+macro_rules! describe_kind {
+    ($($t:ty),*) => {
+        $(
+            impl Describe for $t {
+                fn describe(&self) -> String {
+                    format!("{}: {}", self.kind(), self)
+                }
+            }
+        )*
+    };
+}
+
+describe_kind!(Codepoint, Variant, Reference, QueryCode, Reading);
+
+impl Describe for Radical {
+    fn describe(&self) -> String {
+        format!("{}: {}", self.kind, self.radical as u8)
+    }
+}
+
+impl Describe for String {
+    fn describe(&self) -> String {
+        self.clone()
+    }
+}
+
+impl Describe for char {
+    fn describe(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Describe for u8 {
+    fn describe(&self) -> String {
+        self.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, Change, Delta};
+    use crate::{database_version::DatabaseVersion, kanjidic::Kanjidic, test_shared::DOC};
+    use kanjidic_types::{Grade, Language, Reference};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn reports_changes() {
+        let old = Kanjidic::try_from(&*DOC).unwrap();
+        let mut new = old.clone();
+        new.header.database_version = DatabaseVersion {
+            year: 2022,
+            version: 1,
+        };
+        let removed = new.characters.remove(1);
+        let mut added = removed.clone();
+        added.literal = '丂';
+        new.characters.push(added);
+        let changed = &mut new.characters[0];
+        changed.grade = Some(Grade::Kyouiku(3));
+        changed.references.push(Reference::Heisig6(1616));
+        changed.reading_meaning_groups[0]
+            .translations
+            .get_mut(&Language::En)
+            .unwrap()
+            .retain(|meaning| meaning != "Asia");
+
+        let diff = diff(&old, &new);
+        assert_eq!(diff.added, vec!['丂']);
+        assert_eq!(diff.removed, vec!['唖']);
+        assert_eq!(
+            diff.changed[&'亜'],
+            vec![
+                Change::Grade {
+                    old: Some(Grade::Jouyou),
+                    new: Some(Grade::Kyouiku(3)),
+                },
+                Change::References(Delta {
+                    added: vec![Reference::Heisig6(1616)],
+                    removed: vec![],
+                }),
+                Change::Meanings {
+                    language: Language::En,
+                    added: vec![],
+                    removed: vec!["Asia".into()],
+                },
+            ]
+        );
+        assert_eq!(
+            diff.to_string(),
+            "2021-176 -> 2022-1
+Added 1: 丂
+Removed 1: 唖
+Changed 1
+亜
+  grade: 8 -> 3
+  + reference heisig6: 1616
+  - meaning en: Asia
+"
+        );
+    }
+
+    #[test]
+    fn same_file() {
+        let kanjidic = Kanjidic::try_from(&*DOC).unwrap();
+        let diff = diff(&kanjidic, &kanjidic);
+        assert!(diff.added.is_empty() && diff.removed.is_empty() && diff.changed.is_empty());
+    }
+}
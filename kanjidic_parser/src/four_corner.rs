@@ -1,9 +1,9 @@
 use crate::{
-    pos_error::PosError,
+    pos_error::{Offset, PosError},
     shared::{self, SharedError},
 };
 use kanjidic_types::{four_corner, FourCorner};
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 use std::convert::TryFrom;
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
@@ -14,6 +14,15 @@ pub enum Error {
     Str(PosError, four_corner::ParseError),
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Str(pos, _) => pos.offset(origin),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<FourCorner, Error> {
     let text = shared::text(&node)?;
     FourCorner::try_from(text).map_err(|err| Error::Str(PosError::from(&node), err))
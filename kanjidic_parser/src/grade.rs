@@ -1,6 +1,9 @@
-use crate::shared::{text_uint, SharedError};
+use crate::{
+    pos_error::Offset,
+    shared::{text_uint, SharedError},
+};
 use kanjidic_types::Grade;
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
 pub enum Error {
@@ -10,6 +13,15 @@ pub enum Error {
     Unrecognized(u8),
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Unrecognized(_) => {}
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Grade, Error> {
     let n: u8 = text_uint(&node)?;
     match n {
@@ -1,9 +1,10 @@
 use crate::{
     database_version::{self, DatabaseVersion},
     date_of_creation::{self, DateOfCreation},
+    pos_error::Offset,
     shared::{text_uint, SharedError},
 };
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -20,6 +21,17 @@ pub enum Error {
     Incomplete,
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::DatabaseVersion(err) => err.offset(origin),
+            Error::DateOfCreation(err) => err.offset(origin),
+            Error::Shared(err) => err.offset(origin),
+            Error::Incomplete => {}
+        }
+    }
+}
+
 /// Contains identification information about the version of the file.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct Header {
@@ -67,7 +79,7 @@ impl<'a, 'input> TryFrom<Node<'a, 'input>> for Header {
         for child in node.children() {
             match child.tag_name().name() {
                 "database_version" => {
-                    builder.database_version = Some(DatabaseVersion::try_from(child)?);
+                    builder.database_version = Some(database_version::from(child)?);
                 }
                 "date_of_creation" => {
                     builder.date_of_creation = Some(DateOfCreation::try_from(child)?);
@@ -1,9 +1,10 @@
 use crate::{
-    character,
+    character::{self, CharacterError},
     header::{self, Header},
+    pos_error::PosError,
 };
 use kanjidic_types::Character;
-use roxmltree::{Document, Node};
+use roxmltree::{Document, Node, ParsingOptions};
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -16,7 +17,41 @@ pub enum Error {
     #[error("Error parsing database version")]
     Header(#[from] header::Error),
     #[error("Error parsing a character")]
-    Character(#[from] character::CharacterError),
+    Character(#[from] CharacterError),
+    #[error("{} characters could not be parsed", .0.len())]
+    TooManyErrors(Vec<Diagnostic>),
+}
+
+/// How to respond to characters that fail to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strictness {
+    /// Fail on the first character that cannot be parsed.
+    Strict,
+    /// Skip any characters that cannot be parsed.
+    Lenient,
+    /// Skip characters that cannot be parsed,
+    /// failing if more than the given number are skipped.
+    Tolerate(usize),
+}
+
+/// A character that was skipped because it could not be parsed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The character's literal, if it could be read.
+    pub literal: Option<char>,
+    /// Where the character's element begins.
+    pub position: PosError,
+    /// Why the character could not be parsed.
+    pub error: CharacterError,
+}
+
+/// The outcome of parsing with a chosen strictness.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Report {
+    /// The header and all characters that were parsed successfully.
+    pub kanjidic: Kanjidic,
+    /// The characters that were skipped.
+    pub diagnostics: Vec<Diagnostic>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
@@ -25,42 +60,98 @@ pub struct Kanjidic {
     pub characters: Vec<Character>,
 }
 
-impl<'a> TryFrom<&'a Document<'a>> for Kanjidic {
-    type Error = Error;
+impl Kanjidic {
+    /// Parses the contents of a KANJIDIC2 file,
+    /// handling bad characters according to the given strictness.
+    pub fn parse(xml: &str, strictness: Strictness) -> Result<Report, Error> {
+        let doc = document(xml)?;
+        Self::parse_document(&doc, strictness)
+    }
 
-    fn try_from(doc: &'a Document) -> Result<Self, Self::Error> {
+    /// Parses a KANJIDIC2 document,
+    /// handling bad characters according to the given strictness.
+    pub fn parse_document(doc: &Document, strictness: Strictness) -> Result<Report, Error> {
         let root = doc.root_element();
         let header = Header::try_from(
             root.children()
                 .find(|child| child.has_tag_name("header"))
                 .ok_or(Error::MissingHeader)?,
         )?;
-        let characters: Result<Vec<Character>, character::CharacterError> = root
+        let mut characters = vec![];
+        let mut diagnostics = vec![];
+        for node in root
             .children()
             .filter(|child| child.has_tag_name("character"))
-            .collect::<Vec<Node>>()
-            .iter()
-            .map(|node| character::from(*node))
-            .collect::<Result<Vec<Character>, character::CharacterError>>();
-        let characters = characters?;
-        Ok(Self { header, characters })
+        {
+            match character::from(node) {
+                Ok(character) => characters.push(character),
+                Err(error) => {
+                    if strictness == Strictness::Strict {
+                        return Err(Error::Character(error));
+                    }
+                    diagnostics.push(Diagnostic {
+                        literal: literal(&node),
+                        position: PosError::from(&node),
+                        error,
+                    });
+                }
+            }
+        }
+        if let Strictness::Tolerate(limit) = strictness {
+            if diagnostics.len() > limit {
+                return Err(Error::TooManyErrors(diagnostics));
+            }
+        }
+        Ok(Report {
+            kanjidic: Self { header, characters },
+            diagnostics,
+        })
+    }
+}
+
+fn literal(character: &Node) -> Option<char> {
+    character
+        .children()
+        .find(|child| child.has_tag_name("literal"))
+        .and_then(|literal| literal.text())
+        .and_then(|text| character::string_to_char(text).ok())
+}
+
+impl<'a> TryFrom<&'a Document<'a>> for Kanjidic {
+    type Error = Error;
+
+    fn try_from(doc: &'a Document) -> Result<Self, Self::Error> {
+        Self::parse_document(doc, Strictness::Strict).map(|report| report.kanjidic)
     }
 }
 
 impl TryFrom<&str> for Kanjidic {
     type Error = Error;
 
+    /// Parses the contents of a KANJIDIC2 file as distributed by EDRDG,
+    /// including its DOCTYPE declaration and internal DTD subset.
     fn try_from(xml: &str) -> Result<Self, Self::Error> {
-        let doc = roxmltree::Document::parse(xml)?;
+        let doc = document(xml)?;
         Self::try_from(&doc)
     }
 }
 
+/// Builds the XML tree for a KANJIDIC2 file. The file carries its DTD inline,
+/// which has to be allowed explicitly. Since nothing is skipped,
+/// node positions are lines and columns in the file itself.
+pub fn document(xml: &str) -> Result<Document<'_>, roxmltree::Error> {
+    let options = ParsingOptions {
+        allow_dtd: true,
+        ..ParsingOptions::default()
+    };
+    Document::parse_with_options(xml, options)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Kanjidic;
-    use crate::test_shared::DOC;
-    use roxmltree::Document;
+    use super::{Error, Kanjidic, Strictness};
+    use crate::{character::CharacterError, radical, test_shared::DOC};
+    use roxmltree::{Document, TextPos};
     use std::convert::TryFrom;
 
     #[test]
@@ -68,4 +159,89 @@ mod tests {
     fn kanjidic() {
         let _kanjidic = Kanjidic::try_from(&DOC as &Document).unwrap();
     }
+
+    #[test]
+    fn reports_file_position() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE kanjidic2 [
+<!ELEMENT kanjidic2 (header,character*)>
+<!ELEMENT literal (#PCDATA)>
+<!ELEMENT rad_value (#PCDATA)>
+<!ATTLIST rad_value rad_type CDATA #REQUIRED>
+]>
+<kanjidic2>
+<header>
+<file_version>4</file_version>
+<database_version>2021-176</database_version>
+<date_of_creation>2021-06-25</date_of_creation>
+</header>
+<character>
+<literal>亜</literal>
+<radical>
+  <rad_value rad_type="bogus">7</rad_value>
+</radical>
+</character>
+</kanjidic2>
+"#;
+        match Kanjidic::try_from(xml) {
+            Err(Error::Character(CharacterError::Radical(radical::Error::Kind(pos)))) => {
+                assert_eq!(pos.position(), TextPos::new(17, 3));
+                assert_eq!(pos.to_string(), "Location 17:3");
+            }
+            other => panic!("Expected a radical error, got {:?}", other),
+        }
+    }
+
+    const PARTLY_BROKEN: &str = r#"<kanjidic2>
+<header>
+<file_version>4</file_version>
+<database_version>2021-176</database_version>
+<date_of_creation>2021-06-25</date_of_creation>
+</header>
+<character>
+<literal>亜</literal>
+<misc><stroke_count>7</stroke_count></misc>
+<dic_number><dic_ref dr_type="moro" m_vol="1" m_page="0525">272Q</dic_ref></dic_number>
+</character>
+<character>
+<literal>唖</literal>
+<misc><stroke_count>10</stroke_count></misc>
+</character>
+</kanjidic2>
+"#;
+
+    #[test]
+    fn strict_fails_on_bad_character() {
+        let report = Kanjidic::parse(PARTLY_BROKEN, Strictness::Strict);
+        assert!(matches!(report, Err(Error::Character(_))));
+    }
+
+    #[test]
+    fn lenient_skips_bad_character() {
+        let report = Kanjidic::parse(PARTLY_BROKEN, Strictness::Lenient).unwrap();
+        let literals: Vec<_> = report
+            .kanjidic
+            .characters
+            .iter()
+            .map(|character| character.literal)
+            .collect();
+        assert_eq!(literals, vec!['唖']);
+        assert_eq!(report.diagnostics.len(), 1);
+        let diagnostic = &report.diagnostics[0];
+        assert_eq!(diagnostic.literal, Some('亜'));
+        assert_eq!(diagnostic.position.position(), TextPos::new(7, 1));
+        assert!(matches!(
+            diagnostic.error,
+            CharacterError::DictionaryReference(_)
+        ));
+    }
+
+    #[test]
+    fn tolerates_up_to_limit() {
+        assert!(Kanjidic::parse(PARTLY_BROKEN, Strictness::Tolerate(1)).is_ok());
+        match Kanjidic::parse(PARTLY_BROKEN, Strictness::Tolerate(0)) {
+            Err(Error::TooManyErrors(diagnostics)) => assert_eq!(diagnostics.len(), 1),
+            other => panic!("Expected too many errors, got {:?}", other),
+        }
+    }
 }
@@ -1,9 +1,10 @@
 use crate::{
-    pos_error::PosError,
+    pos_error::{Offset, PosError},
+    reading::is_jouyou,
     shared::{text, SharedError},
 };
 use kanjidic_types::{kunyomi, Kunyomi};
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 use std::convert::TryFrom;
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
@@ -14,9 +15,23 @@ pub enum Error {
     Parse(PosError, kunyomi::ParseError),
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Parse(pos, _) => pos.offset(origin),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Kunyomi, Error> {
     let text = text(&node)?;
-    Kunyomi::try_from(text).map_err(|err| Error::Parse(PosError::from(&node), err))
+    let kunyomi =
+        Kunyomi::try_from(text).map_err(|err| Error::Parse(PosError::from(&node), err))?;
+    Ok(Kunyomi {
+        jouyou: is_jouyou(&node),
+        ..kunyomi
+    })
 }
 
 #[cfg(test)]
@@ -44,6 +59,7 @@ mod tests {
                 kind: KunyomiKind::Normal,
                 reading: "つ".into(),
                 okurigana: Some("ぐ".into()),
+                jouyou: false,
             })
         )
     }
@@ -1,9 +1,9 @@
 use crate::{
-    pos_error::PosError,
+    pos_error::{Offset, PosError},
     shared::{text, SharedError},
 };
 use kanjidic_types::{kuten, Kuten};
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 use std::convert::TryFrom;
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
@@ -14,6 +14,15 @@ pub enum Error {
     Parse(PosError, kuten::ParseError),
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Parse(pos, _) => pos.offset(origin),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Kuten, Error> {
     Kuten::try_from(text(&node)?).map_err(|err| Error::Parse(PosError::from(&node), err))
 }
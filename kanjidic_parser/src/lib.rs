@@ -4,6 +4,7 @@ pub mod codepoint;
 pub mod database_version;
 pub mod date_of_creation;
 pub mod de_roo;
+pub mod diff;
 pub mod four_corner;
 pub mod grade;
 pub mod header;
@@ -12,6 +13,7 @@ pub mod kunyomi;
 pub mod kuten;
 pub mod moro;
 pub mod oneill;
+pub mod onyomi;
 pub mod pin_yin;
 pub mod pos_error;
 pub mod query_code;
@@ -21,9 +23,11 @@ pub mod reference;
 pub mod shared;
 pub mod skip;
 pub mod spahn_hadamitzky;
+pub mod stream;
 pub mod stroke_count;
 pub mod translation;
 pub mod variant;
+pub mod writer;
 
 #[cfg(test)]
 mod test_shared;
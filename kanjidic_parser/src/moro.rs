@@ -1,51 +1,40 @@
 use crate::{
-    pos_error::PosError,
+    pos_error::{Offset, PosError},
     shared::{attr_uint, text, SharedError},
 };
-use kanjidic_types::{moro::MoroSuffix, take_uint, IResult, Moro, NomErrorReason};
-use nom::{bytes::complete::take_while, combinator::map_res, sequence::tuple};
-use roxmltree::Node;
+use kanjidic_types::{moro, Moro};
+use roxmltree::{Node, TextPos};
+use std::convert::TryFrom;
 
 #[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
 pub enum Error {
     #[error("(Moro) Shared: {0}")]
     Shared(#[from] SharedError),
-    #[error("(Moro) Unknown index suffix")]
-    IndexSuffix,
-    #[error("(Moro) Format: {0}, {1}")]
-    Format(PosError, NomErrorReason),
+    #[error("(Moro) Parsing: {0}, {1}")]
+    Parse(PosError, moro::ParseError),
+}
+
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Parse(pos, _) => pos.offset(origin),
+        }
+    }
 }
 
 pub fn from(node: Node) -> Result<Moro, Error> {
-    let (_i, (index, suffix)) = parse_index(text(&node)?)
-        .map_err(|err| Error::Format(PosError::from(&node), err.into()))?;
+    let moro =
+        Moro::try_from(text(&node)?).map_err(|err| Error::Parse(PosError::from(&node), err))?;
     let volume = attr_uint::<u8>(&node, "m_vol")?;
     let page = attr_uint::<u16>(&node, "m_page")?;
     Ok(Moro {
         volume,
         page,
-        index,
-        suffix,
+        ..moro
     })
 }
 
-fn parse_index(s: &str) -> IResult<(u16, MoroSuffix)> {
-    tuple((take_uint, index_suffix))(s)
-}
-
-fn index_suffix(s: &str) -> IResult<MoroSuffix> {
-    map_res(
-        take_while(|c: char| c.is_ascii_alphabetic()),
-        |text| match text {
-            "X" => Ok(MoroSuffix::X),
-            "P" => Ok(MoroSuffix::P),
-            "PX" => Ok(MoroSuffix::PX),
-            "" => Ok(MoroSuffix::None),
-            _ => Err(Error::IndexSuffix),
-        },
-    )(s)
-}
-
 #[cfg(test)]
 mod tests {
     use super::from;
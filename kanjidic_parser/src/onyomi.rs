@@ -0,0 +1,71 @@
+use crate::{
+    pos_error::Offset,
+    reading::is_jouyou,
+    shared::{text, SharedError},
+};
+use kanjidic_types::{onyomi::OnyomiKind, Onyomi};
+use roxmltree::{Node, TextPos};
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
+pub enum Error {
+    #[error("(Onyomi) Shared: {0}")]
+    Shared(#[from] SharedError),
+}
+
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        let Error::Shared(err) = self;
+        err.offset(origin);
+    }
+}
+
+pub fn from(node: Node) -> Result<Onyomi, Error> {
+    let kind = match node.attribute("on_type") {
+        Some("kan") => Some(OnyomiKind::Kan),
+        Some("go") => Some(OnyomiKind::Go),
+        Some("tou") => Some(OnyomiKind::Tou),
+        Some("kan'you") => Some(OnyomiKind::Kanyou),
+        Some(kind) => Some(OnyomiKind::Other(kind.into())),
+        None => None,
+    };
+    Ok(Onyomi {
+        reading: text(&node)?.into(),
+        kind,
+        jouyou: is_jouyou(&node),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from;
+    use kanjidic_types::{onyomi::OnyomiKind, Onyomi};
+    use roxmltree::Document;
+
+    #[test]
+    fn onyomi_attributes() {
+        let xml = r#"<reading r_type="ja_on" on_type="kan'you" r_status="jy">ア</reading>"#;
+        let doc = Document::parse(xml).unwrap();
+        assert_eq!(
+            from(doc.root_element()),
+            Ok(Onyomi {
+                reading: "ア".into(),
+                kind: Some(OnyomiKind::Kanyou),
+                jouyou: true,
+            })
+        )
+    }
+
+    #[test]
+    fn keeps_unknown_kinds() {
+        let xml = r#"<reading r_type="ja_on" on_type="kan'on">ア</reading>"#;
+        let doc = Document::parse(xml).unwrap();
+        assert_eq!(
+            from(doc.root_element()),
+            Ok(Onyomi {
+                reading: "ア".into(),
+                kind: Some(OnyomiKind::Other("kan'on".into())),
+                jouyou: false,
+            })
+        )
+    }
+}
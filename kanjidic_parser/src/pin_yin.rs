@@ -1,9 +1,9 @@
 use crate::{
-    pos_error::PosError,
+    pos_error::{Offset, PosError},
     shared::{self, SharedError},
 };
 use kanjidic_types::{pin_yin, PinYin};
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 use std::convert::TryFrom;
 
 #[derive(thiserror::Error, Debug, PartialEq, Eq, Clone)]
@@ -14,6 +14,15 @@ pub enum Error {
     Parse(PosError, pin_yin::ParseError),
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Parse(pos, _) => pos.offset(origin),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<PinYin, Error> {
     let text = shared::text(&node)?;
     PinYin::try_from(text).map_err(|err| Error::Parse(PosError::from(&node), err))
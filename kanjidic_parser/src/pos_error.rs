@@ -6,16 +6,16 @@ pub struct PosError {
     position: TextPos,
 }
 
+impl PosError {
+    /// The line and column at which the error occurred.
+    pub fn position(&self) -> TextPos {
+        self.position
+    }
+}
+
 impl fmt::Display for PosError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Todo: 331 is currently where the kanjidic contents start,
-        // should make this dynamic
-        write!(
-            f,
-            "Location {}:{}",
-            self.position.row + 331,
-            self.position.col
-        )
+        write!(f, "Location {}:{}", self.position.row, self.position.col)
     }
 }
 
@@ -27,3 +27,22 @@ impl<'a, 'input> From<&Node<'a, 'input>> for PosError {
         Self { position }
     }
 }
+
+/// Errors whose positions can be moved from a document built from a fragment
+/// of the source file, such as a single character element, into the file.
+pub(crate) trait Offset {
+    /// Moves positions relative to the fragment to be relative to the file,
+    /// given where the fragment begins.
+    fn offset(&mut self, origin: TextPos);
+}
+
+impl Offset for PosError {
+    fn offset(&mut self, origin: TextPos) {
+        let relative = self.position;
+        self.position = if relative.row == 1 {
+            TextPos::new(origin.row, origin.col + relative.col - 1)
+        } else {
+            TextPos::new(origin.row + relative.row - 1, relative.col)
+        };
+    }
+}
@@ -1,21 +1,19 @@
 use crate::{
     de_roo, four_corner,
-    pos_error::PosError,
-    shared::{attr, SharedError},
+    pos_error::{Offset, PosError},
+    shared::{attr, text, SharedError},
     skip, spahn_hadamitzky,
 };
 use kanjidic_types::{
     query_code::{Misclassification, MisclassificationKind},
     QueryCode,
 };
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
 pub enum Error {
     #[error("(Query code) Shared: {0}")]
     Shared(#[from] SharedError),
-    #[error("(Query code) Unknown qc_type attribute: {0}")]
-    UnknownType(PosError),
     #[error("(Query code) Skip code: {0}")]
     Skip(#[from] skip::SkipError),
     #[error("(Query code) Spahn Hadamitzky descriptor: {0}")]
@@ -28,6 +26,19 @@ pub enum Error {
     UnknownMisclassification(PosError),
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Skip(err) => err.offset(origin),
+            Error::SpahnHadamitzky(err) => err.offset(origin),
+            Error::FourCorner(err) => err.offset(origin),
+            Error::DeRoo(err) => err.offset(origin),
+            Error::UnknownMisclassification(pos) => pos.offset(origin),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<QueryCode, Error> {
     let qc_type = attr(&node, "qc_type")?;
     match qc_type {
@@ -59,7 +70,10 @@ pub fn from(node: Node) -> Result<QueryCode, Error> {
         "sh_desc" => Ok(QueryCode::SpahnHadamitzky(spahn_hadamitzky::from(node)?)),
         "four_corner" => Ok(QueryCode::FourCorner(four_corner::from(node)?)),
         "deroo" => Ok(QueryCode::DeRoo(de_roo::from(node)?)),
-        _ => Err(Error::UnknownType(PosError::from(&node))),
+        _ => Ok(QueryCode::Other {
+            kind: qc_type.into(),
+            value: text(&node)?.into(),
+        }),
     }
 }
 
@@ -87,4 +101,17 @@ mod tests {
             })))
         )
     }
+
+    #[test]
+    fn unknown_type() {
+        let xml = r#"<q_code qc_type="new_code">3-4-5</q_code>"#;
+        let doc = roxmltree::Document::parse(xml).unwrap();
+        assert_eq!(
+            from(doc.root_element()),
+            Ok(QueryCode::Other {
+                kind: "new_code".into(),
+                value: "3-4-5".into(),
+            })
+        )
+    }
 }
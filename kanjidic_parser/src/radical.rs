@@ -1,9 +1,9 @@
 use crate::{
-    pos_error::PosError,
+    pos_error::{Offset, PosError},
     shared::{attr, text_uint, SharedError},
 };
 use kanjidic_types::{radical::RadicalKind, KangXi, Radical, TryFromPrimitiveError};
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 use std::convert::TryFrom;
 
 #[derive(Debug, PartialEq, Eq, Clone, thiserror::Error)]
@@ -16,6 +16,16 @@ pub enum Error {
     Kind(PosError),
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::OutOfRange(_) => {}
+            Error::Kind(pos) => pos.offset(origin),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Radical, Error> {
     let kang_xi_number: u8 = text_uint(&node)?;
     let kang_xi = KangXi::try_from(kang_xi_number)?;
@@ -1,23 +1,34 @@
 use crate::{
-    kunyomi, pin_yin,
-    pos_error::PosError,
+    kunyomi, onyomi, pin_yin,
+    pos_error::Offset,
     shared::{attr, text, SharedError},
 };
 use kanjidic_types::Reading;
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
 pub enum Error {
     #[error("(Reading) Shared: {0}")]
     Shared(#[from] SharedError),
-    #[error("(Reading) qc_type not recognized: {0}")]
-    UnrecognizedType(PosError),
     #[error("(Reading) Pin yin: {0}")]
     PinYin(#[from] pin_yin::Error),
+    #[error("(Reading) Onyomi: {0}")]
+    Onyomi(#[from] onyomi::Error),
     #[error("(Reading) Kunyomi: {0}")]
     Kunyomi(#[from] kunyomi::Error),
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::PinYin(err) => err.offset(origin),
+            Error::Onyomi(err) => err.offset(origin),
+            Error::Kunyomi(err) => err.offset(origin),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Reading, Error> {
     let r_type = attr(&node, "r_type")?;
     match r_type {
@@ -25,12 +36,20 @@ pub fn from(node: Node) -> Result<Reading, Error> {
         "korean_r" => Ok(Reading::KoreanRomanized(text(&node)?.into())),
         "korean_h" => Ok(Reading::KoreanHangul(text(&node)?.into())),
         "vietnam" => Ok(Reading::Vietnam(text(&node)?.into())),
-        "ja_on" => Ok(Reading::Onyomi(text(&node)?.into())),
+        "ja_on" => Ok(Reading::Onyomi(onyomi::from(node)?)),
         "ja_kun" => Ok(Reading::Kunyomi(kunyomi::from(node)?)),
-        _ => Err(Error::UnrecognizedType(PosError::from(&node))),
+        _ => Ok(Reading::Other {
+            kind: r_type.into(),
+            value: text(&node)?.into(),
+        }),
     }
 }
 
+/// Whether the reading is marked as approved for the jouyou kanji.
+pub fn is_jouyou(node: &Node) -> bool {
+    node.attribute("r_status") == Some("jy")
+}
+
 #[cfg(test)]
 mod tests {
     use super::from;
@@ -52,4 +71,17 @@ mod tests {
             }))
         )
     }
+
+    #[test]
+    fn unknown_type() {
+        let xml = r#"<reading r_type="new_lang">abc</reading>"#;
+        let doc = roxmltree::Document::parse(xml).unwrap();
+        assert_eq!(
+            from(doc.root_element()),
+            Ok(Reading::Other {
+                kind: "new_lang".into(),
+                value: "abc".into(),
+            })
+        )
+    }
 }
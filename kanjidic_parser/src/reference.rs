@@ -1,17 +1,15 @@
 use crate::{
     busy_people, moro, oneill,
-    pos_error::PosError,
-    shared::{attr, text_uint, SharedError},
+    pos_error::Offset,
+    shared::{attr, text, text_uint, SharedError},
 };
 use kanjidic_types::Reference;
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
 pub enum Error {
     #[error("(Reference) Shared: {0}")]
     Shared(#[from] SharedError),
-    #[error("(Reference) Unknown dr_type string: {0}")]
-    UnknownType(PosError),
     #[error("(Reference) Moro: {0}")]
     Moro(#[from] moro::Error),
     #[error("(Reference) Busy People: {0}")]
@@ -20,8 +18,20 @@ pub enum Error {
     Oneill(#[from] oneill::Error),
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Moro(err) => err.offset(origin),
+            Error::BusyPeople(err) => err.offset(origin),
+            Error::Oneill(err) => err.offset(origin),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Reference, Error> {
-    match attr(&node, "dr_type")? {
+    let dr_type = attr(&node, "dr_type")?;
+    match dr_type {
         "nelson_c" => Ok(Reference::NelsonClassic(text_uint(&node)?)),
         "nelson_n" => Ok(Reference::NelsonNew(text_uint(&node)?)),
         "halpern_njecd" => Ok(Reference::Njecd(text_uint(&node)?)),
@@ -46,7 +56,10 @@ pub fn from(node: Node) -> Result<Reference, Error> {
         "busy_people" => Ok(Reference::BusyPeople(busy_people::from(node)?)),
         "kodansha_compact" => Ok(Reference::KodanshaCompact(text_uint(&node)?)),
         "maniette" => Ok(Reference::Maniette(text_uint(&node)?)),
-        _ => Err(Error::UnknownType(PosError::from(&node))),
+        _ => Ok(Reference::Other {
+            kind: dr_type.into(),
+            value: text(&node)?.into(),
+        }),
     }
 }
 
@@ -65,4 +78,17 @@ mod tests {
         let dictionary_reference = from(node);
         assert_eq!(dictionary_reference, Ok(Reference::NelsonClassic(43)))
     }
+
+    #[test]
+    fn unknown_type() {
+        let xml = r#"<dic_ref dr_type="new_book">12</dic_ref>"#;
+        let doc = roxmltree::Document::parse(xml).unwrap();
+        assert_eq!(
+            from(doc.root_element()),
+            Ok(Reference::Other {
+                kind: "new_book".into(),
+                value: "12".into(),
+            })
+        )
+    }
 }
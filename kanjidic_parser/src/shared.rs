@@ -1,5 +1,5 @@
-use crate::pos_error::PosError;
-use roxmltree::Node;
+use crate::pos_error::{Offset, PosError};
+use roxmltree::{Node, TextPos};
 use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
@@ -18,6 +18,19 @@ pub enum SharedError {
     Hex(PosError),
 }
 
+impl Offset for SharedError {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            SharedError::MissingChild(pos, _)
+            | SharedError::MissingAttribute(pos, _)
+            | SharedError::NoText(pos)
+            | SharedError::TextUint(pos)
+            | SharedError::AttrUint(pos)
+            | SharedError::Hex(pos) => pos.offset(origin),
+        }
+    }
+}
+
 pub fn children<'a, 'input, T, E, F>(
     node: &Node<'a, 'input>,
     tag: &'static str,
@@ -49,10 +62,7 @@ pub fn text<'a>(node: &Node<'a, '_>) -> Result<&'a str, SharedError> {
         .ok_or_else(|| SharedError::NoText(PosError::from(node)))
 }
 
-pub fn attr<'a>(
-    node: &Node<'a, '_>,
-    attribute: &'static str,
-) -> Result<&'a str, SharedError> {
+pub fn attr<'a>(node: &Node<'a, '_>, attribute: &'static str) -> Result<&'a str, SharedError> {
     node.attribute(attribute)
         .ok_or_else(|| SharedError::MissingAttribute(PosError::from(node), attribute))
 }
@@ -1,9 +1,9 @@
 use crate::{
-    pos_error::PosError,
+    pos_error::{Offset, PosError},
     shared::{text, SharedError},
 };
 use kanjidic_types::{skip, Skip};
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 use std::convert::TryFrom;
 
 #[derive(thiserror::Error, Debug, PartialEq, Eq)]
@@ -14,6 +14,15 @@ pub enum SkipError {
     Parse(PosError, skip::ParseError),
 }
 
+impl Offset for SkipError {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            SkipError::Shared(err) => err.offset(origin),
+            SkipError::Parse(pos, _) => pos.offset(origin),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Skip, SkipError> {
     let text = text(&node)?;
     Skip::try_from(text).map_err(|err| SkipError::Parse(PosError::from(&node), err))
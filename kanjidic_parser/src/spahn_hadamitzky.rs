@@ -1,9 +1,9 @@
 use crate::{
-    pos_error::PosError,
+    pos_error::{Offset, PosError},
     shared::{self, SharedError},
 };
 use kanjidic_types::{spahn_hadamitzky, ShDesc};
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 use std::convert::TryFrom;
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
@@ -14,6 +14,15 @@ pub enum ShError {
     Parse(PosError, spahn_hadamitzky::descriptor::ParseError),
 }
 
+impl Offset for ShError {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            ShError::Shared(err) => err.offset(origin),
+            ShError::Parse(pos, _) => pos.offset(origin),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<ShDesc, ShError> {
     let text = shared::text(&node)?;
     ShDesc::try_from(text).map_err(|err| ShError::Parse(PosError::from(&node), err))
@@ -0,0 +1,294 @@
+use crate::{
+    character::{self, CharacterError},
+    header::{self, Header},
+    pos_error::Offset,
+};
+use kanjidic_types::Character;
+use quick_xml::{events::Event, Reader};
+use roxmltree::{Document, Node, TextPos};
+use std::{
+    cmp::min,
+    convert::TryFrom,
+    io::{self, BufRead, BufReader, Read},
+};
+
+/// Error while streaming the contents of a KANJIDIC2 file.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("(Stream) XML: {0}")]
+    Xml(#[from] quick_xml::Error),
+    #[error("(Stream) Element: {0}")]
+    Element(#[from] roxmltree::Error),
+    #[error("(Stream) Element is not valid UTF-8: {0}")]
+    Utf8(#[from] std::str::Utf8Error),
+    #[error("(Stream) Could not find header node")]
+    MissingHeader,
+    #[error("(Stream) Header: {0}")]
+    Header(#[from] header::Error),
+    #[error("(Stream) Character: {0}")]
+    Character(#[from] CharacterError),
+}
+
+/// Pulls characters out of a KANJIDIC2 file as they are read.
+///
+/// Rather than building a tree for the whole file, only one
+/// `<character>` element is held in memory at a time.
+/// The header is read up front and is available immediately.
+pub struct KanjidicStream<R: Read> {
+    reader: Reader<Tracker<BufReader<R>>>,
+    header: Header,
+    buffer: Vec<u8>,
+    depth: usize,
+    done: bool,
+}
+
+impl<R: Read> KanjidicStream<R> {
+    /// Reads up to the end of the header, leaving
+    /// the characters to be pulled by iteration.
+    pub fn new(reader: R) -> Result<Self, Error> {
+        let mut reader = Reader::from_reader(Tracker::new(BufReader::new(reader)));
+        let mut buffer = vec![];
+        let mut depth = 0;
+        let header = loop {
+            match next_element(&mut reader, &mut buffer, &mut depth)? {
+                Some((b"header", origin)) => {
+                    break parse_element(&reader, origin, |node| Header::try_from(node))?;
+                }
+                Some((b"character", _)) | None => return Err(Error::MissingHeader),
+                Some(_) => {}
+            }
+        };
+        Ok(Self {
+            reader,
+            header,
+            buffer,
+            depth,
+            done: false,
+        })
+    }
+
+    /// The header of the file.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    fn next_character(&mut self) -> Result<Option<Character>, Error> {
+        loop {
+            match next_element(&mut self.reader, &mut self.buffer, &mut self.depth)? {
+                Some((b"character", origin)) => {
+                    let character = parse_element(&self.reader, origin, character::from)?;
+                    return Ok(Some(character));
+                }
+                Some(_) => {}
+                None => return Ok(None),
+            }
+        }
+    }
+}
+
+impl<R: Read> Iterator for KanjidicStream<R> {
+    type Item = Result<Character, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_character() {
+            Ok(Some(character)) => Some(Ok(character)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                // Past a tokenizer error there is no telling
+                // where the next element starts.
+                if let Error::Xml(_) = err {
+                    self.done = true;
+                }
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+type ElementStart<'a> = (&'a [u8], TextPos);
+
+/// Reads through the next child of the root element, returning
+/// its tag name and where it starts. The element's markup is left
+/// in the tracker.
+fn next_element<'b, R: BufRead>(
+    reader: &mut Reader<Tracker<R>>,
+    buffer: &'b mut Vec<u8>,
+    depth: &mut usize,
+) -> Result<Option<ElementStart<'b>>, Error> {
+    loop {
+        let start = reader.buffer_position();
+        reader.get_mut().mark(start);
+        let origin = reader.get_ref().position;
+        buffer.clear();
+        match reader.read_event_into(buffer)? {
+            Event::Start(tag) if *depth == 1 => {
+                let end = tag.to_end().into_owned();
+                let mut skipped = vec![];
+                reader.read_to_end_into(end.name(), &mut skipped)?;
+                let name_len = tag.name().as_ref().len();
+                return Ok(Some((&buffer[..name_len], origin)));
+            }
+            Event::Empty(tag) if *depth == 1 => {
+                let name_len = tag.name().as_ref().len();
+                return Ok(Some((&buffer[..name_len], origin)));
+            }
+            Event::Start(_) => *depth += 1,
+            Event::End(_) => *depth = depth.saturating_sub(1),
+            Event::Eof => return Ok(None),
+            _ => {}
+        }
+    }
+}
+
+/// Parses the markup of the element the reader just passed, which starts
+/// at the given position in the file. Positions in errors are offset
+/// from the element to the file.
+fn parse_element<R, T, E, F>(reader: &Reader<Tracker<R>>, origin: TextPos, f: F) -> Result<T, Error>
+where
+    F: FnOnce(Node) -> Result<T, E>,
+    E: Offset + Into<Error>,
+{
+    let markup = reader.get_ref().retained(reader.buffer_position());
+    let xml = std::str::from_utf8(markup)?;
+    let doc = Document::parse(xml)?;
+    f(doc.root_element()).map_err(|mut err| {
+        err.offset(origin);
+        err.into()
+    })
+}
+
+/// Keeps the bytes that the XML reader has consumed since the last mark,
+/// along with the line and column in the file at which they begin.
+struct Tracker<R> {
+    inner: R,
+    retained: Vec<u8>,
+    offset: u64,
+    position: TextPos,
+}
+
+impl<R> Tracker<R> {
+    fn new(inner: R) -> Self {
+        Self {
+            inner,
+            retained: vec![],
+            offset: 0,
+            position: TextPos::new(1, 1),
+        }
+    }
+
+    /// Forgets the bytes that come before the given file offset.
+    fn mark(&mut self, offset: u64) {
+        let count = min((offset - self.offset) as usize, self.retained.len());
+        for &byte in &self.retained[..count] {
+            if byte == b'\n' {
+                self.position.row += 1;
+                self.position.col = 1;
+            } else if !is_continuation(byte) {
+                self.position.col += 1;
+            }
+        }
+        self.retained.drain(..count);
+        self.offset += count as u64;
+    }
+
+    /// The bytes from the last mark up to the given file offset.
+    fn retained(&self, offset: u64) -> &[u8] {
+        let count = min((offset - self.offset) as usize, self.retained.len());
+        &self.retained[..count]
+    }
+}
+
+fn is_continuation(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+impl<R: BufRead> Read for Tracker<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.inner.read(buf)?;
+        self.retained.extend_from_slice(&buf[..count]);
+        Ok(count)
+    }
+}
+
+impl<R: BufRead> BufRead for Tracker<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amount: usize) {
+        if amount > 0 {
+            // The inner buffer is not empty, so this does not read.
+            if let Ok(buffer) = self.inner.fill_buf() {
+                self.retained.extend_from_slice(&buffer[..amount]);
+            }
+        }
+        self.inner.consume(amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, KanjidicStream};
+    use crate::{
+        character::{self, CharacterError},
+        header::Header,
+        radical,
+        test_shared::{DOC, FILE},
+    };
+    use roxmltree::TextPos;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn matches_document() {
+        let stream = KanjidicStream::new(FILE).unwrap();
+        let header = DOC
+            .descendants()
+            .find(|node| node.has_tag_name("header"))
+            .unwrap();
+        assert_eq!(stream.header(), &Header::try_from(header).unwrap());
+        let expected: Vec<_> = DOC
+            .root_element()
+            .children()
+            .filter(|child| child.has_tag_name("character"))
+            .filter_map(|node| character::from(node).ok())
+            .collect();
+        let streamed: Vec<_> = stream.filter_map(Result::ok).collect();
+        assert_eq!(streamed, expected);
+    }
+
+    #[test]
+    fn reports_file_position() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<kanjidic2>
+<header>
+<file_version>4</file_version>
+<database_version>2021-176</database_version>
+<date_of_creation>2021-06-25</date_of_creation>
+</header>
+<character><literal>亜</literal><radical><rad_value rad_type="bogus">7</rad_value></radical></character>
+<character>
+<literal>唖</literal>
+<radical>
+<rad_value rad_type="bogus">30</rad_value>
+</radical>
+</character>
+</kanjidic2>
+"#;
+        let positions: Vec<_> = KanjidicStream::new(xml.as_bytes())
+            .unwrap()
+            .map(|result| match result {
+                Err(Error::Character(CharacterError::Radical(radical::Error::Kind(pos)))) => {
+                    pos.position()
+                }
+                other => panic!("Expected a radical error, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(positions, vec![TextPos::new(8, 41), TextPos::new(12, 1)]);
+    }
+}
@@ -1,9 +1,9 @@
 use crate::{
-    pos_error::PosError,
+    pos_error::{Offset, PosError},
     shared::{text_uint, SharedError},
 };
 use kanjidic_types::StrokeCount;
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 
 #[derive(Debug, PartialEq, Eq, thiserror::Error)]
 pub enum Error {
@@ -15,6 +15,16 @@ pub enum Error {
     Incomplete,
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Accepted(pos) => pos.offset(origin),
+            Error::Incomplete => {}
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct StrokeCountBuilder {
     accepted: Option<u8>,
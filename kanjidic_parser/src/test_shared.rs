@@ -1,11 +1,11 @@
+use crate::kanjidic;
 use roxmltree::Document;
 
+pub const FILE: &[u8] = include_bytes!("../../assets/kanjidic2.xml");
+
 lazy_static! {
     pub static ref DOC: Document<'static> = {
-        let file = include_bytes!("../../assets/kanjidic2.xml");
-        let xml = std::str::from_utf8(file).unwrap();
-        let start = xml.find("<kanjidic2>").unwrap();
-        let skipped = std::str::from_utf8(&xml.as_bytes()[start..]).unwrap();
-        roxmltree::Document::parse(skipped).unwrap()
+        let xml = std::str::from_utf8(FILE).unwrap();
+        kanjidic::document(xml).unwrap()
     };
 }
@@ -1,24 +1,37 @@
-use crate::shared::{self, SharedError};
-use kanjidic_types::character::Translations;
-use roxmltree::Node;
+use crate::{
+    pos_error::{Offset, PosError},
+    shared::{self, SharedError},
+};
+use kanjidic_types::{character::Translations, language, Language};
+use roxmltree::{Node, TextPos};
+use std::convert::TryFrom;
 
 #[derive(Debug, thiserror::Error, PartialEq, Eq, Clone)]
 pub enum Error {
     #[error("Translation shared: {0}")]
     Shared(#[from] SharedError),
+    #[error("Translation language: {0}, {1}")]
+    Language(PosError, language::ParseError),
+}
+
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Language(pos, _) => pos.offset(origin),
+        }
+    }
 }
 
 pub fn add_meaning(translations: &mut Translations, meaning: &Node) -> Result<(), Error> {
     let text = shared::text(meaning)?.to_owned();
-    let language = meaning.attribute("m_lang").unwrap_or("en").to_owned();
-    match translations.entry(language) {
-        std::collections::hash_map::Entry::Occupied(mut entry) => {
-            entry.get_mut().push(text);
-        }
-        std::collections::hash_map::Entry::Vacant(entry) => {
-            entry.insert(vec![text]);
+    let language = match meaning.attribute("m_lang") {
+        Some(code) => {
+            Language::try_from(code).map_err(|err| Error::Language(PosError::from(meaning), err))?
         }
-    }
+        None => Language::En,
+    };
+    translations.entry(language).or_default().push(text);
     Ok(())
 }
 
@@ -26,9 +39,9 @@ pub fn add_meaning(translations: &mut Translations, meaning: &Node) -> Result<()
 mod tests {
     use super::{add_meaning, Error};
     use crate::test_shared::DOC;
-    use kanjidic_types::character::Translations;
-    use roxmltree::Node;
-    use std::{collections::HashMap, iter::FromIterator};
+    use kanjidic_types::{character::Translations, language::ParseError, Language};
+    use roxmltree::{Document, Node, TextPos};
+    use std::{collections::BTreeMap, iter::FromIterator};
 
     // Just keeping this around for now for the test
     pub fn from(node: Node) -> Result<Translations, Error> {
@@ -51,9 +64,9 @@ mod tests {
         let translation = from(node);
         assert_eq!(
             translation,
-            Ok(HashMap::from_iter([
+            Ok(BTreeMap::from_iter([
                 (
-                    "en".to_owned(),
+                    Language::En,
                     vec![
                         "Asia".to_owned(),
                         "rank next".to_owned(),
@@ -62,7 +75,7 @@ mod tests {
                     ]
                 ),
                 (
-                    "fr".to_owned(),
+                    Language::Fr,
                     vec![
                         "Asie".to_owned(),
                         "suivant".to_owned(),
@@ -71,7 +84,7 @@ mod tests {
                     ]
                 ),
                 (
-                    "pt".to_owned(),
+                    Language::Pt,
                     vec![
                         "Ásia".to_owned(),
                         "próxima".to_owned(),
@@ -80,7 +93,7 @@ mod tests {
                     ]
                 ),
                 (
-                    "es".to_owned(),
+                    Language::Es,
                     vec![
                         "pref. para indicar".to_owned(),
                         "venir después de".to_owned(),
@@ -90,4 +103,17 @@ mod tests {
             ]))
         )
     }
+
+    #[test]
+    fn reports_unknown_language() {
+        let xml = r#"<rmgroup><meaning m_lang="english">Asia</meaning></rmgroup>"#;
+        let doc = Document::parse(xml).unwrap();
+        match from(doc.root_element()) {
+            Err(Error::Language(pos, err)) => {
+                assert_eq!(pos.position(), TextPos::new(1, 10));
+                assert_eq!(err, ParseError::Code("english".into()));
+            }
+            other => panic!("Expected a language error, got {:?}", other),
+        }
+    }
 }
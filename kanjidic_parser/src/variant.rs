@@ -1,16 +1,14 @@
 use crate::{
     de_roo, kuten, oneill,
-    pos_error::PosError,
-    shared::{attr, text_hex, text_uint, SharedError},
+    pos_error::Offset,
+    shared::{attr, text, text_hex, text_uint, SharedError},
     spahn_hadamitzky::{self, ShError},
 };
 use kanjidic_types::Variant;
-use roxmltree::Node;
+use roxmltree::{Node, TextPos};
 
 #[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
 pub enum Error {
-    #[error("(Variant) var_type not recognized: {0}")]
-    UnknownVariant(PosError),
     #[error("(Variant) Shared: {0}")]
     Shared(#[from] SharedError),
     #[error("(Variant) Kuten code: {0}")]
@@ -23,6 +21,18 @@ pub enum Error {
     ONeill(#[from] oneill::Error),
 }
 
+impl Offset for Error {
+    fn offset(&mut self, origin: TextPos) {
+        match self {
+            Error::Shared(err) => err.offset(origin),
+            Error::Kuten(err) => err.offset(origin),
+            Error::DeRoo(err) => err.offset(origin),
+            Error::SpahnHadamitzky(err) => err.offset(origin),
+            Error::ONeill(err) => err.offset(origin),
+        }
+    }
+}
+
 pub fn from(node: Node) -> Result<Variant, Error> {
     let variant_type = attr(&node, "var_type")?;
     match variant_type {
@@ -35,7 +45,10 @@ pub fn from(node: Node) -> Result<Variant, Error> {
         "nelson_c" => Ok(Variant::Nelson(text_uint::<u16>(&node)?)),
         "oneill" => Ok(Variant::ONeill(oneill::from(node)?)),
         "ucs" => Ok(Variant::Unicode(text_hex(&node)?)),
-        _ => Err(Error::UnknownVariant(PosError::from(&node))),
+        _ => Ok(Variant::Other {
+            kind: variant_type.into(),
+            value: text(&node)?.into(),
+        }),
     }
 }
 
@@ -61,4 +74,17 @@ mod tests {
             }))
         )
     }
+
+    #[test]
+    fn unknown_type() {
+        let xml = r#"<variant var_type="new_jis">1-2-3</variant>"#;
+        let doc = roxmltree::Document::parse(xml).unwrap();
+        assert_eq!(
+            from(doc.root_element()),
+            Ok(Variant::Other {
+                kind: "new_jis".into(),
+                value: "1-2-3".into(),
+            })
+        )
+    }
 }
@@ -0,0 +1,484 @@
+use crate::{
+    database_version::DatabaseVersion, date_of_creation::DateOfCreation, header::Header,
+    kanjidic::Kanjidic,
+};
+use kanjidic_types::{
+    character::ReadingMeaningGroup, query_code::Misclassification, Character, Kunyomi, Language,
+    Moro, Onyomi, QueryCode, Reading, Reference,
+};
+use std::io::{self, Write};
+
+/// Error while writing a KANJIDIC2 file.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("(Writer) IO: {0}")]
+    Io(#[from] io::Error),
+    #[error("(Writer) The DTD requires at least one codepoint: {0}")]
+    NoCodepoints(char),
+    #[error("(Writer) The DTD requires at least one radical: {0}")]
+    NoRadicals(char),
+}
+
+/// The document type declaration distributed with KANJIDIC2,
+/// without the explanatory comments.
+pub const DTD: &str = r#"<!DOCTYPE kanjidic2 [
+<!ELEMENT kanjidic2 (header,character*)>
+<!ELEMENT header (file_version,database_version,date_of_creation)>
+<!ELEMENT file_version (#PCDATA)>
+<!ELEMENT database_version (#PCDATA)>
+<!ELEMENT date_of_creation (#PCDATA)>
+<!ELEMENT character (literal,codepoint, radical, misc, dic_number?, query_code?, reading_meaning?)*>
+<!ELEMENT literal (#PCDATA)>
+<!ELEMENT codepoint (cp_value+)>
+<!ELEMENT cp_value (#PCDATA)>
+<!ATTLIST cp_value cp_type CDATA #REQUIRED>
+<!ELEMENT radical (rad_value+)>
+<!ELEMENT rad_value (#PCDATA)>
+<!ATTLIST rad_value rad_type CDATA #REQUIRED>
+<!ELEMENT misc (grade?, stroke_count+, variant*, freq?, rad_name*,jlpt?)>
+<!ELEMENT rad_name (#PCDATA)>
+<!ELEMENT grade (#PCDATA)>
+<!ELEMENT stroke_count (#PCDATA)>
+<!ELEMENT variant (#PCDATA)>
+<!ATTLIST variant var_type CDATA #REQUIRED>
+<!ELEMENT freq (#PCDATA)>
+<!ELEMENT jlpt (#PCDATA)>
+<!ELEMENT dic_number (dic_ref+)>
+<!ELEMENT dic_ref (#PCDATA)>
+<!ATTLIST dic_ref dr_type CDATA #REQUIRED>
+<!ATTLIST dic_ref m_vol CDATA #IMPLIED>
+<!ATTLIST dic_ref m_page CDATA #IMPLIED>
+<!ELEMENT query_code (q_code+)>
+<!ELEMENT q_code (#PCDATA)>
+<!ATTLIST q_code qc_type CDATA #REQUIRED>
+<!ATTLIST q_code skip_misclass CDATA #IMPLIED>
+<!ELEMENT reading_meaning (rmgroup*, nanori*)>
+<!ELEMENT rmgroup (reading*, meaning*)>
+<!ELEMENT reading (#PCDATA)>
+<!ATTLIST reading r_type CDATA #REQUIRED>
+<!ATTLIST reading on_type CDATA #IMPLIED>
+<!ATTLIST reading r_status CDATA #IMPLIED>
+<!ELEMENT meaning (#PCDATA)>
+<!ATTLIST meaning m_lang CDATA #IMPLIED>
+<!ELEMENT nanori (#PCDATA)>
+]>"#;
+
+/// Renders the contents of a KANJIDIC2 file,
+/// including the XML declaration and DTD.
+pub fn to_string(kanjidic: &Kanjidic) -> Result<String, Error> {
+    let mut out = vec![];
+    write(&mut out, kanjidic)?;
+    Ok(String::from_utf8(out).expect("All written text is UTF-8"))
+}
+
+/// Writes the contents of a KANJIDIC2 file,
+/// including the XML declaration and DTD.
+pub fn write<W: Write>(w: &mut W, kanjidic: &Kanjidic) -> Result<(), Error> {
+    writeln!(w, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(w, "{}", DTD)?;
+    writeln!(w, "<kanjidic2>")?;
+    write_header(w, &kanjidic.header)?;
+    for character in kanjidic.characters.iter() {
+        write_character(w, character)?;
+    }
+    writeln!(w, "</kanjidic2>")?;
+    Ok(())
+}
+
+/// Writes a `<header>` element.
+pub fn write_header<W: Write>(w: &mut W, header: &Header) -> io::Result<()> {
+    let DatabaseVersion { year, version } = header.database_version;
+    let DateOfCreation {
+        year: y,
+        month,
+        day,
+    } = header.date_of_creation;
+    writeln!(w, "<header>")?;
+    writeln!(w, "<file_version>{}</file_version>", header.file_version)?;
+    writeln!(
+        w,
+        "<database_version>{}-{}</database_version>",
+        year, version
+    )?;
+    writeln!(
+        w,
+        "<date_of_creation>{}-{:02}-{:02}</date_of_creation>",
+        y, month, day
+    )?;
+    writeln!(w, "</header>")
+}
+
+/// Writes a `<character>` element. Characters without codepoints
+/// or radicals are rejected, since the DTD requires both.
+pub fn write_character<W: Write>(w: &mut W, character: &Character) -> Result<(), Error> {
+    if character.codepoints.is_empty() {
+        return Err(Error::NoCodepoints(character.literal));
+    }
+    if character.radicals.is_empty() {
+        return Err(Error::NoRadicals(character.literal));
+    }
+    writeln!(w, "<character>")?;
+    writeln!(w, "<literal>{}</literal>", character.literal)?;
+
+    writeln!(w, "<codepoint>")?;
+    for codepoint in character.codepoints.iter() {
+        writeln!(
+            w,
+            r#"<cp_value cp_type="{}">{}</cp_value>"#,
+            codepoint.kind(),
+            codepoint
+        )?;
+    }
+    writeln!(w, "</codepoint>")?;
+
+    writeln!(w, "<radical>")?;
+    for radical in character.radicals.iter() {
+        writeln!(
+            w,
+            r#"<rad_value rad_type="{}">{}</rad_value>"#,
+            radical.kind, radical.radical as u8
+        )?;
+    }
+    writeln!(w, "</radical>")?;
+
+    write_misc(w, character)?;
+
+    if !character.references.is_empty() {
+        writeln!(w, "<dic_number>")?;
+        for reference in character.references.iter() {
+            write_reference(w, reference)?;
+        }
+        writeln!(w, "</dic_number>")?;
+    }
+
+    if !character.query_codes.is_empty() {
+        writeln!(w, "<query_code>")?;
+        for query_code in character.query_codes.iter() {
+            write_query_code(w, query_code)?;
+        }
+        writeln!(w, "</query_code>")?;
+    }
+
+    if !character.reading_meaning_groups.is_empty() || !character.nanori.is_empty() {
+        writeln!(w, "<reading_meaning>")?;
+        for group in character.reading_meaning_groups.iter() {
+            write_rmgroup(w, group)?;
+        }
+        for nanori in character.nanori.iter() {
+            writeln!(w, "<nanori>{}</nanori>", escape(nanori))?;
+        }
+        writeln!(w, "</reading_meaning>")?;
+    }
+
+    writeln!(w, "</character>")?;
+    Ok(())
+}
+
+fn write_misc<W: Write>(w: &mut W, character: &Character) -> io::Result<()> {
+    writeln!(w, "<misc>")?;
+    if let Some(grade) = character.grade {
+        writeln!(w, "<grade>{}</grade>", grade.level())?;
+    }
+    let stroke_counts = &character.stroke_counts;
+    writeln!(w, "<stroke_count>{}</stroke_count>", stroke_counts.accepted)?;
+    for miscount in stroke_counts.miscounts.iter() {
+        writeln!(w, "<stroke_count>{}</stroke_count>", miscount)?;
+    }
+    for variant in character.variants.iter() {
+        writeln!(
+            w,
+            r#"<variant var_type="{}">{}</variant>"#,
+            escape(variant.kind()),
+            escape(&variant.to_string())
+        )?;
+    }
+    if let Some(frequency) = character.frequency {
+        writeln!(w, "<freq>{}</freq>", frequency)?;
+    }
+    for name in character.radical_names.iter() {
+        writeln!(w, "<rad_name>{}</rad_name>", escape(name))?;
+    }
+    if let Some(jlpt) = character.jlpt {
+        writeln!(w, "<jlpt>{}</jlpt>", jlpt)?;
+    }
+    writeln!(w, "</misc>")
+}
+
+fn write_reference<W: Write>(w: &mut W, reference: &Reference) -> io::Result<()> {
+    if let Reference::Moro(moro) = reference {
+        return write_moro(w, moro);
+    }
+    writeln!(
+        w,
+        r#"<dic_ref dr_type="{}">{}</dic_ref>"#,
+        escape(reference.kind()),
+        escape(&reference.to_string())
+    )
+}
+
+fn write_moro<W: Write>(w: &mut W, moro: &Moro) -> io::Result<()> {
+    write!(w, r#"<dic_ref dr_type="moro""#)?;
+    if let Some(volume) = moro.volume {
+        write!(w, r#" m_vol="{}""#, volume)?;
+    }
+    if let Some(page) = moro.page {
+        write!(w, r#" m_page="{:04}""#, page)?;
+    }
+    writeln!(w, ">{}</dic_ref>", moro)
+}
+
+fn write_query_code<W: Write>(w: &mut W, query_code: &QueryCode) -> io::Result<()> {
+    if let QueryCode::Misclassification(Misclassification { skip, kind }) = query_code {
+        return writeln!(
+            w,
+            r#"<q_code qc_type="skip" skip_misclass="{}">{}</q_code>"#,
+            kind, skip
+        );
+    }
+    writeln!(
+        w,
+        r#"<q_code qc_type="{}">{}</q_code>"#,
+        escape(query_code.kind()),
+        escape(&query_code.to_string())
+    )
+}
+
+fn write_rmgroup<W: Write>(w: &mut W, group: &ReadingMeaningGroup) -> io::Result<()> {
+    writeln!(w, "<rmgroup>")?;
+    for reading in group.readings.iter() {
+        write_reading(w, reading)?;
+    }
+    // English meanings carry no m_lang attribute and lead the list in the distributed file.
+    let mut languages: Vec<_> = group.translations.keys().collect();
+    languages.sort_by_key(|language| (**language != Language::En, **language));
+    for language in languages {
+        for meaning in group.translations[language].iter() {
+            if *language == Language::En {
+                writeln!(w, "<meaning>{}</meaning>", escape(meaning))?;
+            } else {
+                writeln!(
+                    w,
+                    r#"<meaning m_lang="{}">{}</meaning>"#,
+                    escape(language.code()),
+                    escape(meaning)
+                )?;
+            }
+        }
+    }
+    writeln!(w, "</rmgroup>")
+}
+
+fn write_reading<W: Write>(w: &mut W, reading: &Reading) -> io::Result<()> {
+    match reading {
+        Reading::Onyomi(onyomi) => write_onyomi(w, onyomi),
+        Reading::Kunyomi(kunyomi) => write_kunyomi(w, kunyomi),
+        _ => writeln!(
+            w,
+            r#"<reading r_type="{}">{}</reading>"#,
+            escape(reading.kind()),
+            escape(&reading.to_string())
+        ),
+    }
+}
+
+fn write_onyomi<W: Write>(w: &mut W, onyomi: &Onyomi) -> io::Result<()> {
+    write!(w, r#"<reading r_type="ja_on""#)?;
+    if let Some(kind) = &onyomi.kind {
+        write!(w, r#" on_type="{}""#, escape(&kind.to_string()))?;
+    }
+    if onyomi.jouyou {
+        write!(w, r#" r_status="jy""#)?;
+    }
+    writeln!(w, ">{}</reading>", escape(&onyomi.reading))
+}
+
+fn write_kunyomi<W: Write>(w: &mut W, kunyomi: &Kunyomi) -> io::Result<()> {
+    write!(w, r#"<reading r_type="ja_kun""#)?;
+    if kunyomi.jouyou {
+        write!(w, r#" r_status="jy""#)?;
+    }
+    writeln!(w, ">{}</reading>", escape(&kunyomi.to_string()))
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_string, write_character, Error};
+    use crate::{character, kanjidic::Kanjidic, test_shared::DOC};
+    use kanjidic_types::Character;
+    use roxmltree::Document;
+    use std::convert::TryFrom;
+
+    fn round_trip(character: &Character) -> Character {
+        let mut out = vec![];
+        write_character(&mut out, character).unwrap();
+        let xml = String::from_utf8(out).unwrap();
+        let doc = Document::parse(&xml).unwrap();
+        character::from(doc.root_element()).unwrap()
+    }
+
+    #[test]
+    fn round_trips_document() {
+        let kanjidic = Kanjidic::try_from(&*DOC).unwrap();
+        let xml = to_string(&kanjidic).unwrap();
+        assert_eq!(Kanjidic::try_from(xml.as_str()), Ok(kanjidic));
+    }
+
+    #[test]
+    fn rejects_missing_codepoints_and_radicals() {
+        let kanjidic = Kanjidic::try_from(&*DOC).unwrap();
+        let character = &kanjidic.characters[0];
+        let write = |character: &Character| write_character(&mut vec![], character);
+        let no_codepoints = Character {
+            codepoints: vec![],
+            ..character.clone()
+        };
+        assert!(matches!(
+            write(&no_codepoints),
+            Err(Error::NoCodepoints('亜'))
+        ));
+        let no_radicals = Character {
+            radicals: vec![],
+            ..character.clone()
+        };
+        assert!(matches!(write(&no_radicals), Err(Error::NoRadicals('亜'))));
+    }
+
+    #[test]
+    fn round_trips_each_character() {
+        let kanjidic = Kanjidic::try_from(&*DOC).unwrap();
+        for character in kanjidic.characters.iter() {
+            assert_eq!(&round_trip(character), character);
+        }
+    }
+
+    /// A character with every element and attribute the writer emits,
+    /// including kinds that are not in the distributed file yet.
+    const EVERY_ELEMENT: &str = r#"<character>
+<literal>亜</literal>
+<codepoint>
+<cp_value cp_type="ucs">4e9c</cp_value>
+<cp_value cp_type="jis208">1-16-01</cp_value>
+<cp_value cp_type="jis212">1-34-20</cp_value>
+<cp_value cp_type="jis213">1-14-58</cp_value>
+</codepoint>
+<radical>
+<rad_value rad_type="classical">7</rad_value>
+<rad_value rad_type="nelson_c">1</rad_value>
+</radical>
+<misc>
+<grade>8</grade>
+<stroke_count>7</stroke_count>
+<stroke_count>8</stroke_count>
+<variant var_type="jis208">1-48-19</variant>
+<variant var_type="jis212">1-21-64</variant>
+<variant var_type="jis213">1-14-58</variant>
+<variant var_type="ucs">4e9e</variant>
+<variant var_type="deroo">2542</variant>
+<variant var_type="njecd">3540</variant>
+<variant var_type="s_h">2k4.6</variant>
+<variant var_type="nelson_c">43</variant>
+<variant var_type="oneill">525A</variant>
+<variant var_type="new_index">&lt;12&gt;</variant>
+<freq>1509</freq>
+<rad_name>つぐ</rad_name>
+<jlpt>1</jlpt>
+</misc>
+<dic_number>
+<dic_ref dr_type="nelson_c">43</dic_ref>
+<dic_ref dr_type="nelson_n">81</dic_ref>
+<dic_ref dr_type="halpern_njecd">3540</dic_ref>
+<dic_ref dr_type="halpern_kkd">4354</dic_ref>
+<dic_ref dr_type="halpern_kkld">2204</dic_ref>
+<dic_ref dr_type="halpern_kkld_2ed">2966</dic_ref>
+<dic_ref dr_type="heisig">1809</dic_ref>
+<dic_ref dr_type="heisig6">1950</dic_ref>
+<dic_ref dr_type="gakken">1331</dic_ref>
+<dic_ref dr_type="oneill_names">525A</dic_ref>
+<dic_ref dr_type="oneill_kk">1788</dic_ref>
+<dic_ref dr_type="moro" m_vol="1" m_page="0525">272</dic_ref>
+<dic_ref dr_type="moro">3540</dic_ref>
+<dic_ref dr_type="henshall">997</dic_ref>
+<dic_ref dr_type="sh_kk">1616</dic_ref>
+<dic_ref dr_type="sh_kk2">1724</dic_ref>
+<dic_ref dr_type="sakade">620</dic_ref>
+<dic_ref dr_type="jf_cards">1032</dic_ref>
+<dic_ref dr_type="henshall3">1016</dic_ref>
+<dic_ref dr_type="tutt_cards">1092</dic_ref>
+<dic_ref dr_type="crowley">1500</dic_ref>
+<dic_ref dr_type="kanji_in_context">1818</dic_ref>
+<dic_ref dr_type="busy_people">3.14</dic_ref>
+<dic_ref dr_type="kodansha_compact">35</dic_ref>
+<dic_ref dr_type="maniette">1827</dic_ref>
+<dic_ref dr_type="new_book">12&lt;b&gt;</dic_ref>
+</dic_number>
+<query_code>
+<q_code qc_type="skip">4-7-1</q_code>
+<q_code qc_type="skip" skip_misclass="posn">1-4-3</q_code>
+<q_code qc_type="skip" skip_misclass="stroke_count">4-6-1</q_code>
+<q_code qc_type="skip" skip_misclass="stroke_and_posn">1-4-4</q_code>
+<q_code qc_type="skip" skip_misclass="stroke_diff">4-8-1</q_code>
+<q_code qc_type="sh_desc">0a7.14</q_code>
+<q_code qc_type="four_corner">1010.6</q_code>
+<q_code qc_type="deroo">3273</q_code>
+<q_code qc_type="new_code">a&amp;b</q_code>
+</query_code>
+<reading_meaning>
+<rmgroup>
+<reading r_type="pinyin">ya4</reading>
+<reading r_type="korean_r">a</reading>
+<reading r_type="korean_h">아</reading>
+<reading r_type="vietnam">A</reading>
+<reading r_type="ja_on">ア</reading>
+<reading r_type="ja_on" on_type="kan" r_status="jy">アク</reading>
+<reading r_type="ja_on" on_type="go">アン</reading>
+<reading r_type="ja_on" on_type="tou">アイ</reading>
+<reading r_type="ja_on" on_type="kan'you">アツ</reading>
+<reading r_type="ja_on" on_type="kan'on">アス</reading>
+<reading r_type="ja_kun" r_status="jy">つ.ぐ</reading>
+<reading r_type="ja_kun">あ-</reading>
+<reading r_type="ja_kun">-あ</reading>
+<reading r_type="new_reading">"quoted"</reading>
+<meaning>Asia</meaning>
+<meaning>"quoted" &amp; 'marked'</meaning>
+<meaning m_lang="fr">Asie</meaning>
+<meaning m_lang="es">Asia</meaning>
+<meaning m_lang="pt">Ásia</meaning>
+<meaning m_lang="de">Asien</meaning>
+</rmgroup>
+<rmgroup>
+<reading r_type="ja_kun">つぎ</reading>
+</rmgroup>
+<nanori>や</nanori>
+<nanori>&lt;つぎ&gt;</nanori>
+</reading_meaning>
+</character>"#;
+
+    #[test]
+    fn round_trips_every_element() {
+        let doc = Document::parse(EVERY_ELEMENT).unwrap();
+        let character = character::from(doc.root_element()).unwrap();
+        assert_eq!(character.stroke_counts.miscounts.len(), 1);
+        assert_eq!(character.codepoints.len(), 4);
+        assert_eq!(character.variants.len(), 10);
+        assert_eq!(character.references.len(), 26);
+        assert_eq!(character.query_codes.len(), 9);
+        assert_eq!(character.reading_meaning_groups.len(), 2);
+        assert_eq!(character.nanori.len(), 2);
+        assert_eq!(round_trip(&character), character);
+    }
+}
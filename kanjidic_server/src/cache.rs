@@ -1,10 +1,13 @@
-use kanjidic_types::Character;
+use kanjidic_types::{Character, MeaningIndex, ReadingIndex, ReferenceIndex, SinoIndex};
 use serde::Deserialize;
 use std::collections::{HashMap, HashSet};
 
 pub type KanjiCache = HashMap<char, Character>;
 pub type RadkCache = HashMap<char, Radk>;
-pub type TranslationCache = HashMap<String, Vec<char>>;
+pub type MeaningCache = MeaningIndex;
+pub type ReadingCache = ReadingIndex;
+pub type ReferenceCache = ReferenceIndex;
+pub type SinoCache = SinoIndex;
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 pub struct Radk {
@@ -17,5 +20,8 @@ pub struct Radk {
 pub struct Cache {
     pub kanji: KanjiCache,
     pub radk: RadkCache,
-    pub translations: TranslationCache,
+    pub meanings: MeaningCache,
+    pub readings: ReadingCache,
+    pub references: ReferenceCache,
+    pub sino: SinoCache,
 }
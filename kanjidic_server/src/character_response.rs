@@ -1,17 +1,18 @@
 use kanjidic_types::{
-    character::Translations, Character, Codepoint, Grade, QueryCode, Radical, Reading, Reference,
+    Character, Codepoint, Field, Grade, Language, QueryCode, Radical, Reading, Reference,
     StrokeCount, Variant,
 };
 use serde::Serialize;
-use std::collections::{HashMap, HashSet};
-
-use crate::field::Field;
+use std::collections::{BTreeMap, HashSet};
 
 // Todo: also skip parsing empty arrays?
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct CharacterResponse<'a> {
     pub literal: char,
+    /// The language chosen from the preferences for translations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
     #[serde(skip_serializing_if = "none_or_empty")]
     pub codepoints: Option<&'a [Codepoint]>,
     #[serde(skip_serializing_if = "none_or_empty")]
@@ -32,8 +33,8 @@ pub struct CharacterResponse<'a> {
     pub references: Option<&'a [Reference]>,
     #[serde(skip_serializing_if = "none_or_empty")]
     pub query_codes: Option<&'a [QueryCode]>,
-    #[serde(skip_serializing_if = "none_or_empty")]
-    pub readings: Option<&'a [Reading]>,
+    #[serde(skip_serializing_if = "none_or_empty_vec")]
+    pub readings: Option<Vec<&'a Reading>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub translations: Option<TranslationsResponse<'a>>,
     #[serde(skip_serializing_if = "none_or_empty")]
@@ -49,18 +50,65 @@ fn none_or_empty(value: &Option<&[impl std::any::Any]>) -> bool {
     }
 }
 
-type FilteredTranslations<'a> = HashMap<&'a str, &'a [String]>;
-
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
-#[serde(untagged)]
-pub enum TranslationsResponse<'a> {
-    All(&'a Translations),
-    Some(FilteredTranslations<'a>),
+fn none_or_empty_vec<T>(value: &Option<Vec<T>>) -> bool {
+    match value {
+        Some(array) => array.is_empty(),
+        None => true,
+    }
 }
 
-type Languages = HashSet<String>;
+type TranslationsResponse<'a> = BTreeMap<Language, Vec<&'a str>>;
+
 type Fields = HashSet<Field>;
 
+/// Which translations to include in responses.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Languages {
+    /// Languages to include translations for, or all of them if empty.
+    include: Vec<Language>,
+    /// Languages in order of preference. When given, only translations
+    /// for the first language with meanings are included.
+    prefer: Vec<Language>,
+}
+
+impl Languages {
+    /// Preferences may be given as separate parameters
+    /// or as a single list separated by commas.
+    /// Codes that are not ISO 639-1 codes are reported in `errors`.
+    pub fn new(language: Vec<String>, prefer: Vec<String>, errors: &mut Vec<String>) -> Self {
+        let prefer = prefer
+            .iter()
+            .flat_map(|prefer| prefer.split(','))
+            .map(str::trim)
+            .filter(|prefer| !prefer.is_empty())
+            .map(String::from)
+            .collect();
+        Self {
+            include: parse_languages(language, errors),
+            prefer: parse_languages(prefer, errors),
+        }
+    }
+
+    /// The languages to include translations for, or all of them if empty.
+    pub fn include(&self) -> &[Language] {
+        &self.include
+    }
+}
+
+/// Parses language codes, reporting those that are not ISO 639-1 codes.
+pub fn parse_languages(codes: Vec<String>, errors: &mut Vec<String>) -> Vec<Language> {
+    codes
+        .into_iter()
+        .filter_map(|code| match code.parse() {
+            Ok(language) => Some(language),
+            Err(_) => {
+                errors.push(format!("Unknown language code: {}", code));
+                None
+            }
+        })
+        .collect()
+}
+
 impl<'a, 'b> CharacterResponse<'a> {
     pub fn new(character: &'a Character, fields: &'b Fields, languages: &'b Languages) -> Self {
         if fields.contains(&Field::All) {
@@ -113,7 +161,7 @@ impl<'a, 'b> CharacterResponse<'a> {
             out.query_codes = Some(&character.query_codes);
         }
         if fields.contains(&Field::Readings) {
-            out.readings = Some(&character.readings);
+            out.readings = Some(character.readings().collect());
         }
         if fields.contains(&Field::Nanori) {
             out.nanori = Some(&character.nanori);
@@ -122,37 +170,42 @@ impl<'a, 'b> CharacterResponse<'a> {
             out.decomposition = Some(&character.decomposition)
         }
         if fields.contains(&Field::Translations) {
-            out.translations = Some(Self::translations(&character.translations, languages));
+            out.set_translations(character, languages);
         }
         out
     }
 
-    fn translations(
-        translations: &'a Translations,
-        languages: &'b Languages,
-    ) -> TranslationsResponse<'a> {
-        if languages.is_empty() {
-            TranslationsResponse::All(translations)
+    fn set_translations(&mut self, character: &'a Character, languages: &'b Languages) {
+        if languages.prefer.is_empty() {
+            self.translations = Some(Self::translations(character, languages));
         } else {
-            TranslationsResponse::Some(Self::filtered_translations(translations, languages))
+            let preferred = character.preferred_meanings(&languages.prefer);
+            self.language = preferred.as_ref().map(|(language, _)| *language);
+            self.translations = Some(preferred.into_iter().collect());
         }
     }
 
-    fn filtered_translations(
-        translations: &'a Translations,
+    fn translations(
+        character: &'a Character,
         languages: &'b Languages,
-    ) -> FilteredTranslations<'a> {
-        let out: FilteredTranslations = translations
-            .iter()
-            .filter(|(k, _)| languages.contains(*k))
-            .map(|(k, v)| (k.as_str(), v.as_slice()))
-            .collect();
+    ) -> TranslationsResponse<'a> {
+        let mut out = TranslationsResponse::default();
+        for group in character.reading_meaning_groups.iter() {
+            for (language, meanings) in group.translations.iter() {
+                if languages.include.is_empty() || languages.include.contains(language) {
+                    out.entry(*language)
+                        .or_default()
+                        .extend(meanings.iter().map(String::as_str));
+                }
+            }
+        }
         out
     }
 
     fn all_fields(character: &'a Character, languages: &'b Languages) -> Self {
-        Self {
+        let mut out = Self {
             literal: character.literal,
+            language: None,
             codepoints: Some(&character.codepoints),
             radicals: Some(&character.radicals),
             grade: character.grade,
@@ -163,10 +216,12 @@ impl<'a, 'b> CharacterResponse<'a> {
             jlpt: character.jlpt,
             references: Some(&character.references),
             query_codes: Some(&character.query_codes),
-            readings: Some(&character.readings),
+            readings: Some(character.readings().collect()),
             nanori: Some(&character.nanori),
             decomposition: Some(&character.decomposition),
-            translations: Some(Self::translations(&character.translations, languages)),
-        }
+            translations: None,
+        };
+        out.set_translations(character, languages);
+        out
     }
 }
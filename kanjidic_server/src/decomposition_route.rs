@@ -1,4 +1,8 @@
-use crate::{cache::Cache, character_response::CharacterResponse, field::Field};
+use crate::{
+    cache::Cache,
+    character_response::{CharacterResponse, Languages},
+};
+use kanjidic_types::Field;
 use rocket::{serde::json::Json, State};
 use serde::Serialize;
 use std::collections::HashSet;
@@ -12,11 +16,12 @@ pub struct RadicalsResponse<'a> {
     pub kanji: Vec<CharacterResponse<'a>>,
 }
 
-#[get("/kanji/decomposition/<radicals>?<field>&<language>&<page>&<limit>")]
+#[get("/kanji/decomposition/<radicals>?<field>&<language>&<prefer>&<page>&<limit>")]
 pub async fn decomposition(
     radicals: String,
     field: Vec<Field>,
     language: Vec<String>,
+    prefer: Vec<String>,
     page: Option<u16>,
     limit: Option<u16>,
     cache: &State<Cache>,
@@ -28,7 +33,7 @@ pub async fn decomposition(
     let page = page.unwrap_or(0);
     let mut errors = vec![];
     let field: HashSet<_> = field.into_iter().collect();
-    let language: HashSet<_> = language.into_iter().collect();
+    let language = Languages::new(language, prefer, &mut errors);
     if radicals.is_empty() {
         let valid_next: HashSet<_> = cache.radk.keys().copied().collect();
         return Ok(Json(RadicalsResponse {
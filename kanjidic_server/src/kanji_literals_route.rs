@@ -1,4 +1,8 @@
-use crate::{cache::Cache, character_response::CharacterResponse, field::Field};
+use crate::{
+    cache::Cache,
+    character_response::{CharacterResponse, Languages},
+};
+use kanjidic_types::Field;
 use rocket::{serde::json::Json, State};
 use serde::Serialize;
 use std::collections::HashSet;
@@ -10,11 +14,12 @@ pub struct KanjiResponse<'a> {
     kanji: Vec<CharacterResponse<'a>>,
 }
 
-#[get("/kanji/literals/<literals>?<field>&<language>&<limit>&<page>")]
+#[get("/kanji/literals/<literals>?<field>&<language>&<prefer>&<limit>&<page>")]
 pub async fn kanji(
     literals: String,
     field: Vec<Field>,
     language: Vec<String>,
+    prefer: Vec<String>,
     limit: Option<u16>,
     page: Option<u16>,
     cache: &State<Cache>,
@@ -26,7 +31,7 @@ pub async fn kanji(
     let page = page.unwrap_or(0);
     let mut errors = vec![];
     let field: HashSet<_> = field.into_iter().collect();
-    let language: HashSet<_> = language.into_iter().collect();
+    let language = Languages::new(language, prefer, &mut errors);
     let kanji: Vec<_> = literals
         .chars()
         .filter_map(|s| match cache.kanji.get(&s) {
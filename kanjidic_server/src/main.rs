@@ -5,11 +5,14 @@ mod cache;
 mod character_response;
 mod cors;
 mod decomposition_route;
-mod field;
 mod kanji_literals_route;
 mod radical_response;
 mod radicals_route;
+mod reading_route;
+mod reference_route;
+mod search_route;
 mod shared;
+mod sino_route;
 mod startup;
 mod translation_route;
 
@@ -18,12 +21,17 @@ use clap::Parser;
 use decomposition_route::decomposition;
 use kanji_literals_route::kanji;
 use radicals_route::{radicals_all, radicals_some};
+use reading_route::reading;
+use reference_route::reference;
 use rocket::fairing::AdHoc;
+use search_route::search;
+use sino_route::sino;
 use startup::init_cache;
 use translation_route::translation;
 
 #[derive(Parser)]
 struct Cli {
+    /// A JSON file or binary snapshot produced by kanjidic_converter
     #[clap(short, long)]
     kanji_path: String,
 }
@@ -43,7 +51,11 @@ fn rocket() -> _ {
                 decomposition,
                 radicals_all,
                 radicals_some,
-                translation
+                translation,
+                reading,
+                reference,
+                search,
+                sino
             ],
         )
 }
@@ -0,0 +1,54 @@
+use crate::{
+    cache::Cache,
+    character_response::{CharacterResponse, Languages},
+};
+use kanjidic_types::Field;
+use rocket::{serde::json::Json, State};
+use serde::Serialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingResponse<'a> {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<String>,
+    kanji: Vec<CharacterResponse<'a>>,
+}
+
+/// Finds kanji with `reading` as an onyomi, kunyomi or nanori.
+/// The reading may be given in hiragana, katakana or Hepburn romaji,
+/// and kunyomi match with or without their okurigana.
+/// Results are ordered by frequency.
+#[get("/kanji/reading/<reading>?<field>&<language>&<prefer>&<page>&<limit>")]
+pub async fn reading(
+    reading: String,
+    field: Vec<Field>,
+    language: Vec<String>,
+    prefer: Vec<String>,
+    page: Option<u16>,
+    limit: Option<u16>,
+    cache: &State<Cache>,
+) -> Json<ReadingResponse<'_>> {
+    let limit = match limit {
+        Some(limit) => std::cmp::min(limit, 16),
+        None => 16,
+    } as usize;
+    let page = page.unwrap_or(0);
+    let mut errors = vec![];
+    let field: HashSet<_> = field.into_iter().collect();
+    let language = Languages::new(language, prefer, &mut errors);
+    let kanji = cache
+        .readings
+        .search(&reading)
+        .iter()
+        .filter_map(|literal| {
+            cache
+                .kanji
+                .get(literal)
+                .map(|character| CharacterResponse::new(character, &field, &language))
+        })
+        .skip(page as usize * limit)
+        .take(limit)
+        .collect();
+    Json(ReadingResponse { errors, kanji })
+}
@@ -0,0 +1,83 @@
+use crate::{
+    cache::Cache,
+    character_response::{CharacterResponse, Languages},
+};
+use kanjidic_types::{Field, Reference};
+use rocket::{serde::json::Json, State};
+use serde::Serialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReferenceResponse<'a> {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<String>,
+    kanji: Vec<CharacterResponse<'a>>,
+}
+
+/// The query parameters of a search by reference.
+#[derive(Debug, Clone, PartialEq, Eq, FromForm)]
+pub struct ReferenceQuery {
+    to: Option<String>,
+    field: Vec<Field>,
+    language: Vec<String>,
+    prefer: Vec<String>,
+    page: Option<u16>,
+    limit: Option<u16>,
+}
+
+/// Finds kanji by their index in the dictionary with the dr_type `kind`,
+/// such as `/kanji/reference/heisig6/1809`. Given `to`, finds the kanji
+/// from `reference` to `to` inclusive in dictionary order instead.
+#[get("/kanji/reference/<kind>/<reference>?<query..>")]
+pub async fn reference(
+    kind: String,
+    reference: String,
+    query: ReferenceQuery,
+    cache: &State<Cache>,
+) -> Json<ReferenceResponse<'_>> {
+    let ReferenceQuery {
+        to,
+        field,
+        language,
+        prefer,
+        page,
+        limit,
+    } = query;
+    let limit = match limit {
+        Some(limit) => std::cmp::min(limit, 16),
+        None => 16,
+    } as usize;
+    let page = page.unwrap_or(0);
+    let mut errors = vec![];
+    let mut parse = |value: &str| match Reference::parse(&kind, value) {
+        Ok(reference) => Some(reference),
+        Err(err) => {
+            errors.push(format!("Could not read reference {}: {}", value, err));
+            None
+        }
+    };
+    let start = parse(&reference);
+    let end = to.as_deref().map(parse);
+    let literals = match (start, end) {
+        (Some(start), None) => cache.references.get(&start).to_vec(),
+        (Some(start), Some(Some(end))) => cache.references.range(&start, &end),
+        _ => vec![],
+    };
+    if !cache.references.kinds().any(|known| known == kind) {
+        errors.push(format!("No references of type {}", kind));
+    }
+    let field: HashSet<_> = field.into_iter().collect();
+    let language = Languages::new(language, prefer, &mut errors);
+    let kanji = literals
+        .iter()
+        .filter_map(|literal| {
+            cache
+                .kanji
+                .get(literal)
+                .map(|character| CharacterResponse::new(character, &field, &language))
+        })
+        .skip(page as usize * limit)
+        .take(limit)
+        .collect();
+    Json(ReferenceResponse { errors, kanji })
+}
@@ -0,0 +1,167 @@
+use crate::{
+    cache::Cache,
+    character_response::{parse_languages, CharacterResponse, Languages},
+    shared::string_to_char,
+};
+use kanjidic_types::{Character, Field, Filter, KangXi, Reference};
+use rocket::{serde::json::Json, State};
+use serde::Serialize;
+use std::{cmp::Ordering, collections::HashSet, convert::TryFrom};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SearchResponse<'a> {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<String>,
+    kanji: Vec<CharacterResponse<'a>>,
+    /// Passed as `cursor` to get the next page, if there is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next: Option<char>,
+}
+
+/// The criteria of a search, mirroring [`Filter`].
+#[derive(Debug, Clone, PartialEq, Eq, FromForm)]
+pub struct Criteria {
+    grade: Vec<u8>,
+    jlpt: Vec<u8>,
+    min_strokes: Option<u8>,
+    max_strokes: Option<u8>,
+    miscounts: bool,
+    min_frequency: Option<u16>,
+    max_frequency: Option<u16>,
+    radical: Vec<u8>,
+    nelson_radical: Vec<u8>,
+    has_reference: Vec<String>,
+    has_language: Vec<String>,
+    has_reading: Vec<String>,
+    component: Option<String>,
+}
+
+impl Criteria {
+    fn filter(self, errors: &mut Vec<String>) -> Filter {
+        let mut radicals = |numbers: Vec<u8>| -> Vec<KangXi> {
+            numbers
+                .into_iter()
+                .filter_map(|number| match KangXi::try_from(number) {
+                    Ok(radical) => Some(radical),
+                    Err(_) => {
+                        errors.push(format!("Radical numbers go from 1 to 214: {}", number));
+                        None
+                    }
+                })
+                .collect()
+        };
+        Filter {
+            grades: self.grade,
+            jlpt: self.jlpt,
+            min_strokes: self.min_strokes,
+            max_strokes: self.max_strokes,
+            miscounts: self.miscounts,
+            min_frequency: self.min_frequency,
+            max_frequency: self.max_frequency,
+            classical_radicals: radicals(self.radical),
+            nelson_radicals: radicals(self.nelson_radical),
+            references: self.has_reference,
+            languages: parse_languages(self.has_language, errors),
+            readings: self.has_reading,
+            components: self.component.unwrap_or_default().chars().collect(),
+        }
+    }
+}
+
+/// Finds kanji meeting every given criterion.
+/// `sort` is `frequency`, `strokes`, or the dr_type of a reference
+/// such as `heisig6` to put kanji in the order of that dictionary,
+/// leaving out kanji without it. Otherwise, kanji are sorted by codepoint.
+/// Unknown sorts are reported and fall back to codepoint order.
+/// Pages continue after the kanji given as `cursor`.
+#[get("/kanji/search?<sort>&<field>&<language>&<prefer>&<cursor>&<limit>&<criteria..>")]
+#[allow(clippy::too_many_arguments)]
+pub async fn search(
+    sort: Option<String>,
+    field: Vec<Field>,
+    language: Vec<String>,
+    prefer: Vec<String>,
+    cursor: Option<String>,
+    limit: Option<u16>,
+    criteria: Criteria,
+    cache: &State<Cache>,
+) -> Json<SearchResponse<'_>> {
+    let limit = match limit {
+        Some(limit) => std::cmp::min(limit, 16),
+        None => 16,
+    } as usize;
+    let mut errors = vec![];
+    let filter = criteria.filter(&mut errors);
+    let mut matches: Vec<_> = cache
+        .kanji
+        .values()
+        .filter(|character| filter.matches(character))
+        .collect();
+    let sort = match sort.as_deref() {
+        Some(kind)
+            if !matches!(kind, "frequency" | "strokes")
+                && !cache.references.kinds().any(|known| known == kind) =>
+        {
+            errors.push(format!(
+                "Expected frequency, strokes or a reference type for the sort: {}",
+                kind
+            ));
+            None
+        }
+        sort => sort,
+    };
+    if let Some(kind) = sort.filter(|sort| !matches!(*sort, "frequency" | "strokes")) {
+        matches.retain(|character| reference(character, kind).is_some());
+    }
+    matches.sort_by(|a, b| compare(a, b, sort).then(a.literal.cmp(&b.literal)));
+
+    let start = match cursor {
+        None => 0,
+        Some(cursor) => match matches
+            .iter()
+            .position(|character| Some(character.literal) == string_to_char(&cursor))
+        {
+            Some(position) => position + 1,
+            None => {
+                errors.push(format!("Cursor is not among the results: {}", cursor));
+                matches.len()
+            }
+        },
+    };
+    let page = &matches[start..std::cmp::min(start + limit, matches.len())];
+    let next = match page.last() {
+        Some(last) if start + limit < matches.len() => Some(last.literal),
+        _ => None,
+    };
+    let field: HashSet<_> = field.into_iter().collect();
+    let language = Languages::new(language, prefer, &mut errors);
+    let kanji = page
+        .iter()
+        .map(|character| CharacterResponse::new(character, &field, &language))
+        .collect();
+    Json(SearchResponse {
+        errors,
+        kanji,
+        next,
+    })
+}
+
+/// Orders kanji by the sort parameter, leaving ties to the caller.
+fn compare(a: &Character, b: &Character, sort: Option<&str>) -> Ordering {
+    match sort {
+        None => Ordering::Equal,
+        Some("frequency") => a
+            .frequency
+            .unwrap_or(u16::MAX)
+            .cmp(&b.frequency.unwrap_or(u16::MAX)),
+        Some("strokes") => a.stroke_counts.accepted.cmp(&b.stroke_counts.accepted),
+        Some(kind) => reference(a, kind).cmp(&reference(b, kind)),
+    }
+}
+
+fn reference<'a>(character: &'a Character, kind: &str) -> Option<&'a Reference> {
+    character
+        .references
+        .iter()
+        .find(|reference| reference.kind() == kind)
+}
@@ -0,0 +1,80 @@
+use crate::{
+    cache::Cache,
+    character_response::{CharacterResponse, Languages},
+};
+use kanjidic_types::Field;
+use rocket::{serde::json::Json, State};
+use serde::Serialize;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SinoResponse<'a> {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<String>,
+    kanji: Vec<CharacterResponse<'a>>,
+}
+
+/// The query parameters of a search by reading.
+#[derive(Debug, Clone, PartialEq, Eq, FromForm)]
+pub struct SinoQuery {
+    field: Vec<Field>,
+    language: Vec<String>,
+    prefer: Vec<String>,
+    page: Option<u16>,
+    limit: Option<u16>,
+}
+
+/// Finds kanji by a reading from another language, where `script` is one of
+/// - `pinyin`, written with tone numbers or tone marks, or without a tone
+///   to match any tone
+/// - `korean`, in hangul or revised romanization
+/// - `vietnam`, the Hán-Việt reading, ignoring diacritics
+///
+/// Results are ordered by frequency.
+#[get("/kanji/sino/<script>/<reading>?<query..>")]
+pub async fn sino(
+    script: String,
+    reading: String,
+    query: SinoQuery,
+    cache: &State<Cache>,
+) -> Json<SinoResponse<'_>> {
+    let SinoQuery {
+        field,
+        language,
+        prefer,
+        page,
+        limit,
+    } = query;
+    let limit = match limit {
+        Some(limit) => std::cmp::min(limit, 16),
+        None => 16,
+    } as usize;
+    let page = page.unwrap_or(0);
+    let mut errors = vec![];
+    let literals = match script.as_str() {
+        "pinyin" => cache.sino.pin_yin(&reading),
+        "korean" => cache.sino.korean(&reading),
+        "vietnam" => cache.sino.vietnam(&reading),
+        _ => {
+            errors.push(format!(
+                "Expected pinyin, korean or vietnam for the script: {}",
+                script
+            ));
+            &[]
+        }
+    };
+    let field: HashSet<_> = field.into_iter().collect();
+    let language = Languages::new(language, prefer, &mut errors);
+    let kanji = literals
+        .iter()
+        .filter_map(|literal| {
+            cache
+                .kanji
+                .get(literal)
+                .map(|character| CharacterResponse::new(character, &field, &language))
+        })
+        .skip(page as usize * limit)
+        .take(limit)
+        .collect();
+    Json(SinoResponse { errors, kanji })
+}
@@ -1,12 +1,14 @@
-use std::collections::hash_map::Entry;
-
-use crate::cache::{Cache, KanjiCache, Radk, RadkCache, TranslationCache};
-use kanjidic_types::Character;
+use crate::cache::{Cache, Radk, RadkCache};
+use kanjidic_types::{snapshot, ReferenceIndex, Snapshot};
 use rocket::{
     fairing,
-    tokio::{fs::read_to_string, io},
+    tokio::{io, task},
     Build, Rocket,
 };
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
 use thiserror::Error as ThisError;
 
 #[derive(Debug, ThisError)]
@@ -15,20 +17,37 @@ pub enum InitError {
     Io(#[from] io::Error),
     #[error("{0}")]
     Json(#[from] serde_json::Error),
+    #[error("{0}")]
+    Snapshot(#[from] snapshot::Error),
+    #[error("{0}")]
+    Task(#[from] task::JoinError),
 }
 
 pub async fn init_cache(rocket: Rocket<Build>, kanji_path: String) -> fairing::Result {
-    let (kanji, translations) = match get_kanji_data(kanji_path).await {
+    let Snapshot {
+        characters,
+        meanings,
+        readings,
+        sino,
+    } = match load_kanji_data(kanji_path).await {
         Ok(data) => data,
         Err(e) => {
             eprintln!("{}", e);
             return Err(rocket);
         }
     };
+    let references = ReferenceIndex::new(&characters);
+    let kanji = characters
+        .into_iter()
+        .map(|character| (character.literal, character))
+        .collect();
     let radk = get_radk_data();
     let cache = Cache {
         kanji,
-        translations,
+        meanings,
+        readings,
+        references,
+        sino,
         radk,
     };
     Ok(rocket.manage(cache))
@@ -48,28 +67,17 @@ fn get_radk_data() -> RadkCache {
         .collect()
 }
 
-async fn get_kanji_data(kanji_path: String) -> Result<(KanjiCache, TranslationCache), InitError> {
-    let mut kanji = KanjiCache::default();
-    let mut translations = TranslationCache::default();
-    let file_contents = read_to_string(kanji_path).await?;
-    let kanji_json: Vec<Character> = serde_json::from_str(&file_contents)?;
-    for character in kanji_json {
-        let literal = character.literal;
-        for language in character.translations.values() {
-            for translation in language.iter() {
-                for part in translation.split(' ') {
-                    match translations.entry(part.to_owned()) {
-                        Entry::Occupied(mut entry) => {
-                            entry.get_mut().push(literal);
-                        }
-                        Entry::Vacant(entry) => {
-                            entry.insert(vec![literal]);
-                        }
-                    }
-                }
-            }
-        }
-        kanji.insert(literal, character);
+/// Decodes the kanji off the async runtime, since it reads and parses
+/// the whole file.
+async fn load_kanji_data(kanji_path: String) -> Result<Snapshot, InitError> {
+    task::spawn_blocking(move || get_kanji_data(kanji_path)).await?
+}
+
+fn get_kanji_data(kanji_path: String) -> Result<Snapshot, InitError> {
+    let mut reader = BufReader::new(File::open(kanji_path)?);
+    if Snapshot::is_snapshot(reader.fill_buf()?) {
+        Ok(Snapshot::from_reader(reader)?)
+    } else {
+        Ok(Snapshot::new(serde_json::from_reader(reader)?))
     }
-    Ok((kanji, translations))
 }
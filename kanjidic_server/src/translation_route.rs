@@ -1,4 +1,8 @@
-use crate::{cache::Cache, character_response::CharacterResponse, field::Field};
+use crate::{
+    cache::Cache,
+    character_response::{CharacterResponse, Languages},
+};
+use kanjidic_types::Field;
 use rocket::{serde::json::Json, State};
 use serde::Serialize;
 use std::collections::HashSet;
@@ -6,41 +10,45 @@ use std::collections::HashSet;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TranslationResponse<'a> {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<String>,
     kanji: Vec<CharacterResponse<'a>>,
 }
 
-// Todo: Assert that `translation` is composed of ascii letters
-
-#[get("/kanji/translation/<translation>?<field>&<language>&<page>&<limit>")]
+/// Searches meanings for every word of `translation`, with the last word
+/// matching as a prefix. Results come best match first: kanji with a meaning
+/// equal to the search, then those with all the words, then prefix matches,
+/// each ordered by frequency. When languages are given, only meanings
+/// in those languages are searched.
+#[get("/kanji/translation/<translation>?<field>&<language>&<prefer>&<page>&<limit>")]
 pub async fn translation(
     translation: String,
     field: Vec<Field>,
     language: Vec<String>,
+    prefer: Vec<String>,
     page: Option<u16>,
     limit: Option<u16>,
     cache: &State<Cache>,
 ) -> Json<TranslationResponse<'_>> {
-    let translation = translation.to_lowercase();
     let limit = match limit {
         Some(limit) => std::cmp::min(limit, 16),
         None => 16,
     } as usize;
     let page = page.unwrap_or(0);
+    let mut errors = vec![];
     let field: HashSet<_> = field.into_iter().collect();
-    let language: HashSet<_> = language.into_iter().collect();
-    let kanji = match cache.translations.get(&translation) {
-        Some(literals) => literals
-            .iter()
-            .filter_map(|literal| {
-                cache
-                    .kanji
-                    .get(literal)
-                    .map(|character| CharacterResponse::new(character, &field, &language))
-            })
-            .skip(page as usize * limit)
-            .take(limit)
-            .collect(),
-        None => vec![],
-    };
-    Json(TranslationResponse { kanji })
+    let language = Languages::new(language, prefer, &mut errors);
+    let hits = cache.meanings.search(&translation, language.include());
+    let kanji = hits
+        .iter()
+        .filter_map(|hit| {
+            cache
+                .kanji
+                .get(&hit.literal)
+                .map(|character| CharacterResponse::new(character, &field, &language))
+        })
+        .skip(page as usize * limit)
+        .take(limit)
+        .collect();
+    Json(TranslationResponse { errors, kanji })
 }
@@ -7,7 +7,7 @@ use nom::{
     sequence::tuple,
 };
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fmt, str::FromStr};
 use thiserror::Error;
 
 /// A location in Japanese for Busy People.
@@ -43,6 +43,23 @@ impl TryFrom<&str> for BusyPeople {
     }
 }
 
+impl FromStr for BusyPeople {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for BusyPeople {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.chapter {
+            Some(chapter) => write!(f, "{}.{}", self.volume, chapter),
+            None => write!(f, "{}.A", self.volume),
+        }
+    }
+}
+
 fn parts(s: &str) -> IResult<(u8, char, Option<u8>)> {
     tuple((number, char('.'), chapter))(s)
 }
@@ -56,3 +73,16 @@ fn number(s: &str) -> IResult<u8> {
         s.parse::<u8>()
     })(s)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BusyPeople;
+
+    #[test]
+    fn busy_people_round_trip() {
+        for text in ["3.14", "2.A"] {
+            let parsed: BusyPeople = text.parse().unwrap();
+            assert_eq!(parsed.to_string(), text);
+        }
+    }
+}
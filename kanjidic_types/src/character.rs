@@ -1,8 +1,12 @@
-use crate::{Codepoint, Grade, QueryCode, Radical, Reading, Reference, StrokeCount, Variant};
+use crate::{
+    Codepoint, Grade, Language, QueryCode, Radical, Reading, Reference, StrokeCount, Variant,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
-pub type Translations = HashMap<String, Vec<String>>;
+/// Meanings keyed by language, ordered by language code
+/// so that serialized characters are the same from run to run.
+pub type Translations = BTreeMap<Language, Vec<String>>;
 
 /// Information about a kanji.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -40,11 +44,9 @@ pub struct Character {
     /// Codes used to identify the kanji
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub query_codes: Vec<QueryCode>,
-    /// Different ways the kanji can be read.
+    /// Groups of readings along with the meanings associated with them.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
-    pub readings: Vec<Reading>,
-    /// Translations of the kanji into different languages.
-    pub translations: Translations,
+    pub reading_meaning_groups: Vec<ReadingMeaningGroup>,
     /// Japanese readings associated with names.
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub nanori: Vec<String>,
@@ -52,3 +54,171 @@ pub struct Character {
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub decomposition: Vec<char>,
 }
+
+impl Character {
+    /// Different ways the kanji can be read, across all reading and meaning groups.
+    pub fn readings(&self) -> impl Iterator<Item = &Reading> {
+        self.reading_meaning_groups
+            .iter()
+            .flat_map(|group| group.readings.iter())
+    }
+
+    /// Translations of the kanji into different languages,
+    /// merged across all reading and meaning groups.
+    pub fn translations(&self) -> Translations {
+        let mut out = Translations::default();
+        for group in self.reading_meaning_groups.iter() {
+            for (language, meanings) in group.translations.iter() {
+                out.entry(*language)
+                    .or_default()
+                    .extend(meanings.iter().cloned());
+            }
+        }
+        out
+    }
+
+    /// The meanings in the first of the given languages that the kanji
+    /// has any meanings in, along with that language,
+    /// merged across all reading and meaning groups.
+    pub fn preferred_meanings(&self, languages: &[Language]) -> Option<(Language, Vec<&str>)> {
+        languages.iter().find_map(|language| {
+            let meanings: Vec<_> = self
+                .reading_meaning_groups
+                .iter()
+                .filter_map(|group| group.translations.get(language))
+                .flatten()
+                .map(String::as_str)
+                .collect();
+            if meanings.is_empty() {
+                None
+            } else {
+                Some((*language, meanings))
+            }
+        })
+    }
+}
+
+/// A set of readings and the meanings that go with them.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingMeaningGroup {
+    /// Different ways the kanji can be read.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub readings: Vec<Reading>,
+    /// Translations of the kanji into different languages.
+    pub translations: Translations,
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl Character {
+    /// A character with one stroke and nothing else,
+    /// for tests to fill in with struct update syntax.
+    pub fn test(literal: char) -> Self {
+        Self {
+            literal,
+            codepoints: vec![],
+            radicals: vec![],
+            grade: None,
+            stroke_counts: StrokeCount {
+                accepted: 1,
+                miscounts: vec![],
+            },
+            variants: vec![],
+            frequency: None,
+            radical_names: vec![],
+            jlpt: None,
+            references: vec![],
+            query_codes: vec![],
+            reading_meaning_groups: vec![],
+            nanori: vec![],
+            decomposition: vec![],
+        }
+    }
+
+    /// 亜 with seven strokes, a frequency of 1509, the readings ア and つ.ぐ,
+    /// and the English meanings "Asia" and "rank next".
+    pub fn sample() -> Self {
+        use crate::{kunyomi::KunyomiKind, Kunyomi, Onyomi};
+        Self {
+            stroke_counts: StrokeCount {
+                accepted: 7,
+                miscounts: vec![],
+            },
+            frequency: Some(1509),
+            reading_meaning_groups: vec![ReadingMeaningGroup::test(
+                vec![
+                    Reading::Onyomi(Onyomi {
+                        reading: "ア".into(),
+                        kind: None,
+                        jouyou: true,
+                    }),
+                    Reading::Kunyomi(Kunyomi {
+                        reading: "つ".into(),
+                        okurigana: Some("ぐ".into()),
+                        kind: KunyomiKind::Normal,
+                        jouyou: false,
+                    }),
+                ],
+                &[(Language::En, "Asia"), (Language::En, "rank next")],
+            )],
+            ..Self::test('亜')
+        }
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl ReadingMeaningGroup {
+    /// A group with the given readings and meanings, in order.
+    pub fn test(readings: Vec<Reading>, meanings: &[(Language, &str)]) -> Self {
+        let mut translations = Translations::default();
+        for (language, meaning) in meanings {
+            translations
+                .entry(*language)
+                .or_default()
+                .push(meaning.to_string());
+        }
+        Self {
+            readings,
+            translations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Character, ReadingMeaningGroup, Translations};
+    use crate::Language;
+
+    #[test]
+    fn preferred_meanings() {
+        let group = |language: Language, meanings: &[&str]| {
+            let mut translations = Translations::default();
+            translations.insert(
+                language,
+                meanings.iter().map(|meaning| meaning.to_string()).collect(),
+            );
+            ReadingMeaningGroup {
+                readings: vec![],
+                translations,
+            }
+        };
+        let character = Character {
+            reading_meaning_groups: vec![
+                group(Language::Pt, &[]),
+                group(Language::Es, &["Asia"]),
+                group(Language::Es, &["próximo"]),
+                group(Language::En, &["Asia"]),
+            ],
+            ..Character::test('亜')
+        };
+        assert_eq!(
+            character.preferred_meanings(&[Language::Pt, Language::Es, Language::En]),
+            Some((Language::Es, vec!["Asia", "próximo"]))
+        );
+        assert_eq!(
+            character.preferred_meanings(&[Language::Fr, Language::En]),
+            Some((Language::En, vec!["Asia"]))
+        );
+        assert_eq!(character.preferred_meanings(&[Language::Fr]), None);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::Kuten;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// The code of a kanji in a given character set standard.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -14,3 +15,26 @@ pub enum Codepoint {
     /// Unicode character
     Unicode(u32),
 }
+
+impl Codepoint {
+    /// The cp_type attribute used for the codepoint in KANJIDIC2.
+    pub fn kind(&self) -> &str {
+        match self {
+            Codepoint::Jis208(_) => "jis208",
+            Codepoint::Jis212(_) => "jis212",
+            Codepoint::Jis213(_) => "jis213",
+            Codepoint::Unicode(_) => "ucs",
+        }
+    }
+}
+
+impl fmt::Display for Codepoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Codepoint::Jis208(kuten) | Codepoint::Jis212(kuten) | Codepoint::Jis213(kuten) => {
+                write!(f, "{}", kuten)
+            }
+            Codepoint::Unicode(code) => write!(f, "{:x}", code),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::Character;
+use std::collections::{HashMap, HashSet};
+
+/// The characters of two releases matched up by literal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison<'a> {
+    /// Characters only in the newer release, in its order.
+    pub added: Vec<&'a Character>,
+    /// Characters only in the older release, in its order.
+    pub removed: Vec<&'a Character>,
+    /// The older and newer entries for characters in both releases,
+    /// in the order of the newer release.
+    pub common: Vec<(&'a Character, &'a Character)>,
+}
+
+impl<'a> Comparison<'a> {
+    /// Matches the characters of the older release with those of the newer.
+    pub fn new(old: &'a [Character], new: &'a [Character]) -> Self {
+        let old_characters: HashMap<_, _> = old
+            .iter()
+            .map(|character| (character.literal, character))
+            .collect();
+        let new_literals: HashSet<_> = new.iter().map(|character| character.literal).collect();
+        let mut out = Self {
+            added: vec![],
+            removed: old
+                .iter()
+                .filter(|character| !new_literals.contains(&character.literal))
+                .collect(),
+            common: vec![],
+        };
+        for character in new {
+            match old_characters.get(&character.literal) {
+                Some(&previous) => out.common.push((previous, character)),
+                None => out.added.push(character),
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Comparison;
+    use crate::Character;
+
+    #[test]
+    fn matches_literals() {
+        let old = [Character::test('亜'), Character::test('唖')];
+        let new = [Character::test('阿'), Character::test('亜')];
+        let comparison = Comparison::new(&old, &new);
+        assert_eq!(comparison.added, vec![&new[0]]);
+        assert_eq!(comparison.removed, vec![&old[1]]);
+        assert_eq!(comparison.common, vec![(&old[0], &new[1])]);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{shared::NomErrorReason, take_uint, IResult, NomErr};
+use nom::{character::complete::char, combinator::all_consuming, sequence::tuple};
+use serde::{Deserialize, Serialize};
+use std::{convert::TryFrom, fmt, str::FromStr};
+use thiserror::Error;
+
+/// The version of a KANJIDIC2 release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct DatabaseVersion {
+    /// The year of release.
+    pub year: u16,
+    /// The version that came out in the given year,
+    /// with the counter being reset annually.
+    pub version: u16,
+}
+
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    #[error("(Database version) Format: {0}")]
+    Format(NomErrorReason),
+    #[error("(Database version) Node contains no text")]
+    NoText,
+}
+
+impl<'a> From<NomErr<'a>> for ParseError {
+    fn from(err: NomErr<'a>) -> Self {
+        Self::Format(err.into())
+    }
+}
+
+impl TryFrom<&str> for DatabaseVersion {
+    type Error = ParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        let (_, (year, _, version)) = all_consuming(version_parts)(text)?;
+        Ok(DatabaseVersion { year, version })
+    }
+}
+
+/// Reads the version from a database_version element of KANJIDIC2.
+#[cfg(feature = "roxmltree")]
+impl<'a, 'input> TryFrom<roxmltree::Node<'a, 'input>> for DatabaseVersion {
+    type Error = ParseError;
+
+    fn try_from(node: roxmltree::Node) -> Result<Self, Self::Error> {
+        Self::try_from(node.text().ok_or(ParseError::NoText)?)
+    }
+}
+
+impl FromStr for DatabaseVersion {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+/// Writes the version as it appears in KANJIDIC2.
+impl fmt::Display for DatabaseVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.year, self.version)
+    }
+}
+
+fn version_parts(s: &str) -> IResult<(u16, char, u16)> {
+    tuple((take_uint, char('-'), take_uint))(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DatabaseVersion;
+
+    #[test]
+    fn database_version_round_trip() {
+        for text in ["2021-176", "2022-1"] {
+            let parsed: DatabaseVersion = text.parse().unwrap();
+            assert_eq!(parsed.to_string(), text);
+        }
+    }
+
+    #[test]
+    fn rejects_trailing_input() {
+        assert!("2021-176junk".parse::<DatabaseVersion>().is_err());
+        assert!("2021-176 ".parse::<DatabaseVersion>().is_err());
+    }
+}
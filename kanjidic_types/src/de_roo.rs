@@ -1,7 +1,7 @@
 use num_enum::{TryFromPrimitive, TryFromPrimitiveError};
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fmt, str::FromStr};
 use thiserror::Error;
 
 /// Identification of a kanji in the De Roo system.
@@ -176,6 +176,20 @@ impl TryFrom<&str> for DeRoo {
     }
 }
 
+impl FromStr for DeRoo {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for DeRoo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{:02}", self.top as u8, self.bottom as u8)
+    }
+}
+
 fn from_slices(text: &str, first: usize) -> Result<DeRoo, ParseError> {
     let top = ExtremeTop::try_from(u8_from_slice(text, 0, first)?)?;
     let bottom = ExtremeBottom::try_from(u8_from_slice(text, first, 2)?)?;
@@ -188,3 +202,16 @@ fn u8_from_slice(text: &str, start: usize, count: usize) -> Result<u8, ParseErro
     let top: u8 = top.parse().map_err(|_| ParseError::Number)?;
     Ok(top)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::DeRoo;
+
+    #[test]
+    fn de_roo_round_trip() {
+        for text in ["3273", "1240"] {
+            let parsed: DeRoo = text.parse().unwrap();
+            assert_eq!(parsed.to_string(), text);
+        }
+    }
+}
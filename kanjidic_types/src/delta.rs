@@ -0,0 +1,220 @@
+use crate::{Character, Comparison, DatabaseVersion};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashSet};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("(Delta) Added character {0} is already present")]
+    Present(char),
+    #[error("(Delta) Character {0} is missing")]
+    Missing(char),
+    #[error("(Delta) Checksum {actual} does not match the expected {expected}")]
+    Checksum { expected: String, actual: String },
+    #[error("(Delta) Encoding: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// The changes between the characters of two KANJIDIC2 releases,
+/// for updating a local copy without downloading the whole file again.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Delta {
+    /// The release the delta applies to.
+    pub from: DatabaseVersion,
+    /// The release the delta produces.
+    pub to: DatabaseVersion,
+    /// Characters only in the newer release.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub added: BTreeMap<char, Character>,
+    /// Characters only in the older release.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub removed: Vec<char>,
+    /// The newer entries for characters that differ between the releases.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub changed: BTreeMap<char, Character>,
+    /// The [`checksum`] of the characters of the newer release.
+    pub checksum: String,
+}
+
+impl Delta {
+    /// Finds the characters added, removed and changed between two releases.
+    pub fn new(
+        from: DatabaseVersion,
+        old: &[Character],
+        to: DatabaseVersion,
+        new: &[Character],
+    ) -> Result<Self, Error> {
+        let comparison = Comparison::new(old, new);
+        let added = comparison
+            .added
+            .into_iter()
+            .map(|character| (character.literal, character.clone()))
+            .collect();
+        let mut removed: Vec<_> = comparison
+            .removed
+            .into_iter()
+            .map(|character| character.literal)
+            .collect();
+        removed.sort_unstable();
+        // Any difference counts, including reordered lists, since applying
+        // the delta has to reproduce the newer release exactly.
+        let changed = comparison
+            .common
+            .into_iter()
+            .filter(|(old, new)| old != new)
+            .map(|(_, new)| (new.literal, new.clone()))
+            .collect();
+        Ok(Self {
+            from,
+            to,
+            added,
+            removed,
+            changed,
+            checksum: checksum(new)?,
+        })
+    }
+
+    /// Applies the delta to the characters of the older release,
+    /// checking that the result matches the newer release.
+    /// Characters keep their order, with added characters at the end.
+    pub fn apply(&self, characters: &[Character]) -> Result<Vec<Character>, Error> {
+        let literals: HashSet<_> = characters
+            .iter()
+            .map(|character| character.literal)
+            .collect();
+        if let Some(literal) = self.added.keys().find(|literal| literals.contains(literal)) {
+            return Err(Error::Present(*literal));
+        }
+        if let Some(literal) = self
+            .removed
+            .iter()
+            .chain(self.changed.keys())
+            .find(|literal| !literals.contains(literal))
+        {
+            return Err(Error::Missing(*literal));
+        }
+
+        let removed: HashSet<_> = self.removed.iter().collect();
+        let out: Vec<_> = characters
+            .iter()
+            .filter(|character| !removed.contains(&character.literal))
+            .map(|character| {
+                self.changed
+                    .get(&character.literal)
+                    .unwrap_or(character)
+                    .clone()
+            })
+            .chain(self.added.values().cloned())
+            .collect();
+
+        let actual = checksum(&out)?;
+        if actual != self.checksum {
+            return Err(Error::Checksum {
+                expected: self.checksum.clone(),
+                actual,
+            });
+        }
+        Ok(out)
+    }
+}
+
+/// The SHA-256 digest of a set of characters as a hex string.
+/// The digest covers each character as JSON with sorted keys,
+/// in order of literal, so it does not depend on the order of the set.
+pub fn checksum(characters: &[Character]) -> Result<String, Error> {
+    let mut sorted: Vec<_> = characters.iter().collect();
+    sorted.sort_by_key(|character| character.literal);
+    let mut hasher = Sha256::new();
+    for character in sorted {
+        let value = canonical(serde_json::to_value(character)?);
+        hasher.update(serde_json::to_vec(&value)?);
+        hasher.update(b"\n");
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect())
+}
+
+/// Sorts the keys of every object. Another crate in the build may turn on
+/// serde_json's `preserve_order`, which keeps struct fields in declaration
+/// order instead, and the checksum must not depend on which crates are built.
+fn canonical(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.into_iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, canonical(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(canonical).collect()),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checksum, Delta, Error};
+    use crate::{Character, DatabaseVersion};
+
+    fn releases() -> (Vec<Character>, Vec<Character>, Delta) {
+        let old = vec![
+            Character::test('亜'),
+            Character::test('唖'),
+            Character::test('娃'),
+        ];
+        let new = vec![
+            Character::sample(),
+            Character::test('唖'),
+            Character::test('阿'),
+        ];
+        let delta = Delta::new(
+            DatabaseVersion {
+                year: 2021,
+                version: 176,
+            },
+            &old,
+            DatabaseVersion {
+                year: 2022,
+                version: 1,
+            },
+            &new,
+        )
+        .unwrap();
+        (old, new, delta)
+    }
+
+    #[test]
+    fn finds_changes() {
+        let (_, _, delta) = releases();
+        assert_eq!(delta.added.keys().collect::<Vec<_>>(), vec![&'阿']);
+        assert_eq!(delta.removed, vec!['娃']);
+        assert_eq!(delta.changed.keys().collect::<Vec<_>>(), vec![&'亜']);
+    }
+
+    #[test]
+    fn applies() {
+        let (old, new, delta) = releases();
+        let applied = delta.apply(&old).unwrap();
+        assert_eq!(applied, new);
+        assert_eq!(checksum(&applied).unwrap(), delta.checksum);
+    }
+
+    #[test]
+    fn rejects_mismatches() {
+        let (old, new, delta) = releases();
+        assert!(matches!(delta.apply(&new), Err(Error::Present('阿'))));
+        assert!(matches!(delta.apply(&old[..2]), Err(Error::Missing('娃'))));
+        let mut local = old;
+        local[1].stroke_counts.accepted = 10;
+        assert!(matches!(delta.apply(&local), Err(Error::Checksum { .. })));
+    }
+}
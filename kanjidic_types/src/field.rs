@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// A part of a character to select, as named by the `field` parameter
+/// of kanjidic_server and the `--fields` option of kanjidic_converter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "rocket", derive(rocket::FromFormField))]
+pub enum Field {
+    /// Every other field
+    All,
+    Codepoints,
+    Radicals,
+    Grade,
+    StrokeCounts,
+    Variants,
+    Frequency,
+    RadicalNames,
+    Jlpt,
+    References,
+    QueryCodes,
+    Readings,
+    Translations,
+    Nanori,
+    Decomposition,
+}
+
+impl Field {
+    /// Every field other than `All`, in declaration order.
+    pub const EVERY: [Field; 14] = [
+        Field::Codepoints,
+        Field::Radicals,
+        Field::Grade,
+        Field::StrokeCounts,
+        Field::Variants,
+        Field::Frequency,
+        Field::RadicalNames,
+        Field::Jlpt,
+        Field::References,
+        Field::QueryCodes,
+        Field::Readings,
+        Field::Translations,
+        Field::Nanori,
+        Field::Decomposition,
+    ];
+}
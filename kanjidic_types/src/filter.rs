@@ -0,0 +1,222 @@
+use crate::{radical::RadicalKind, Character, KangXi, Language};
+use serde::{Deserialize, Serialize};
+
+/// Criteria for selecting characters.
+/// Empty or missing criteria match every character.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Filter {
+    /// KANJIDIC2 grade numbers to include.
+    pub grades: Vec<u8>,
+    /// Pre-2010 JLPT levels to include.
+    pub jlpt: Vec<u8>,
+    /// The fewest accepted strokes to include.
+    pub min_strokes: Option<u8>,
+    /// The most accepted strokes to include.
+    pub max_strokes: Option<u8>,
+    /// Whether common miscounts can also meet the stroke bounds.
+    pub miscounts: bool,
+    /// The highest frequency ranking to include.
+    /// Characters without a ranking are left out.
+    pub min_frequency: Option<u16>,
+    /// The lowest frequency ranking to include.
+    /// Characters without a ranking are left out.
+    pub max_frequency: Option<u16>,
+    /// Classical radicals to include.
+    pub classical_radicals: Vec<KangXi>,
+    /// Nelson radicals to include, which are the classical radical
+    /// unless Nelson classifies the character differently.
+    pub nelson_radicals: Vec<KangXi>,
+    /// The dr_type of references that characters must all have.
+    pub references: Vec<String>,
+    /// Languages that characters must all have meanings in.
+    pub languages: Vec<Language>,
+    /// The r_type of readings that characters must all have.
+    pub readings: Vec<String>,
+    /// Radical components that characters must all contain.
+    pub components: Vec<char>,
+}
+
+impl Filter {
+    /// Whether the character meets every criterion.
+    pub fn matches(&self, character: &Character) -> bool {
+        let mut strokes = self.stroke_counts(character);
+        (self.grades.is_empty()
+            || character
+                .grade
+                .is_some_and(|grade| self.grades.contains(&grade.level())))
+            && (self.jlpt.is_empty()
+                || character.jlpt.is_some_and(|jlpt| self.jlpt.contains(&jlpt)))
+            && strokes.any(|strokes| {
+                self.min_strokes.is_none_or(|min| strokes >= min)
+                    && self.max_strokes.is_none_or(|max| strokes <= max)
+            })
+            && self.min_frequency.is_none_or(|min| {
+                character
+                    .frequency
+                    .is_some_and(|frequency| frequency >= min)
+            })
+            && self.max_frequency.is_none_or(|max| {
+                character
+                    .frequency
+                    .is_some_and(|frequency| frequency <= max)
+            })
+            && (self.classical_radicals.is_empty()
+                || radical(character, RadicalKind::Classical)
+                    .is_some_and(|radical| self.classical_radicals.contains(&radical)))
+            && (self.nelson_radicals.is_empty()
+                || radical(character, RadicalKind::Nelson)
+                    .or_else(|| radical(character, RadicalKind::Classical))
+                    .is_some_and(|radical| self.nelson_radicals.contains(&radical)))
+            && self.references.iter().all(|kind| {
+                character
+                    .references
+                    .iter()
+                    .any(|reference| reference.kind() == kind)
+            })
+            && self.languages.iter().all(|language| {
+                character
+                    .reading_meaning_groups
+                    .iter()
+                    .any(|group| group.translations.contains_key(language))
+            })
+            && self
+                .readings
+                .iter()
+                .all(|kind| character.readings().any(|reading| reading.kind() == kind))
+            && self
+                .components
+                .iter()
+                .all(|component| character.decomposition.contains(component))
+    }
+
+    /// The stroke counts to check against the bounds.
+    fn stroke_counts<'a>(&self, character: &'a Character) -> impl Iterator<Item = u8> + 'a {
+        let miscounts: &[u8] = if self.miscounts {
+            &character.stroke_counts.miscounts
+        } else {
+            &[]
+        };
+        std::iter::once(character.stroke_counts.accepted).chain(miscounts.iter().copied())
+    }
+}
+
+fn radical(character: &Character, kind: RadicalKind) -> Option<KangXi> {
+    character
+        .radicals
+        .iter()
+        .find(|radical| radical.kind == kind)
+        .map(|radical| radical.radical)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Filter;
+    use crate::{
+        character::ReadingMeaningGroup, radical::RadicalKind, Character, Grade, KangXi, Language,
+        Radical, Reading, Reference, StrokeCount,
+    };
+
+    #[test]
+    fn matches() {
+        let character = Character {
+            radicals: vec![Radical {
+                kind: RadicalKind::Classical,
+                radical: KangXi::Two,
+            }],
+            grade: Some(Grade::Jouyou),
+            stroke_counts: StrokeCount {
+                accepted: 7,
+                miscounts: vec![8],
+            },
+            frequency: Some(1509),
+            jlpt: Some(1),
+            references: vec![Reference::Heisig6(1616)],
+            reading_meaning_groups: vec![ReadingMeaningGroup::test(
+                vec![Reading::Vietnam("A".into())],
+                &[(Language::En, "Asia")],
+            )],
+            decomposition: vec!['一', '口'],
+            ..Character::test('亜')
+        };
+        let matching = [
+            Filter::default(),
+            Filter {
+                grades: vec![8, 9],
+                jlpt: vec![1],
+                min_strokes: Some(7),
+                max_strokes: Some(7),
+                miscounts: false,
+                min_frequency: Some(1000),
+                max_frequency: Some(1509),
+                classical_radicals: vec![KangXi::Two],
+                nelson_radicals: vec![KangXi::Two],
+                references: vec!["heisig6".into()],
+                languages: vec![Language::En],
+                readings: vec!["vietnam".into()],
+                components: vec!['口'],
+            },
+            Filter {
+                min_strokes: Some(8),
+                miscounts: true,
+                ..Default::default()
+            },
+        ];
+        for filter in matching.iter() {
+            assert!(filter.matches(&character), "{:?}", filter);
+        }
+        let failing = [
+            Filter {
+                grades: vec![1],
+                ..Default::default()
+            },
+            Filter {
+                jlpt: vec![2],
+                ..Default::default()
+            },
+            Filter {
+                min_strokes: Some(8),
+                ..Default::default()
+            },
+            Filter {
+                max_strokes: Some(6),
+                ..Default::default()
+            },
+            Filter {
+                min_frequency: Some(1510),
+                ..Default::default()
+            },
+            Filter {
+                max_frequency: Some(500),
+                ..Default::default()
+            },
+            Filter {
+                classical_radicals: vec![KangXi::One],
+                ..Default::default()
+            },
+            Filter {
+                nelson_radicals: vec![KangXi::One],
+                ..Default::default()
+            },
+            Filter {
+                references: vec!["heisig".into()],
+                ..Default::default()
+            },
+            Filter {
+                languages: vec![Language::Fr],
+                ..Default::default()
+            },
+            Filter {
+                readings: vec!["ja_on".into()],
+                ..Default::default()
+            },
+            Filter {
+                components: vec!['口', '亠'],
+                ..Default::default()
+            },
+        ];
+        for filter in failing.iter() {
+            assert!(!filter.matches(&character), "{:?}", filter);
+        }
+    }
+}
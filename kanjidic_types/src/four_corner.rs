@@ -2,7 +2,7 @@ use crate::TryFromPrimitiveError;
 use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
-use std::{convert::TryFrom, str::Chars};
+use std::{convert::TryFrom, fmt, str::Chars, str::FromStr};
 use thiserror::Error;
 
 /// A kanji classification using the Four Corner system.
@@ -88,20 +88,46 @@ impl TryFrom<&str> for FourCorner {
         let top_right = take_stroke(&mut iter)?;
         let bottom_left = take_stroke(&mut iter)?;
         let bottom_right = take_stroke(&mut iter)?;
-        if iter.next() != Some('.') {
-            return Err(ParseError::Pattern);
-        }
-        let fifth_corner = take_stroke(&mut iter)?;
+        let fifth_corner = match iter.next() {
+            Some('.') => Some(take_stroke(&mut iter)?),
+            Some(_) => return Err(ParseError::Pattern),
+            None => None,
+        };
         Ok(FourCorner {
             top_left,
             top_right,
             bottom_left,
             bottom_right,
-            fifth_corner: Some(fifth_corner),
+            fifth_corner,
         })
     }
 }
 
+impl FromStr for FourCorner {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for FourCorner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}",
+            self.top_left as u8,
+            self.top_right as u8,
+            self.bottom_left as u8,
+            self.bottom_right as u8
+        )?;
+        if let Some(fifth_corner) = self.fifth_corner {
+            write!(f, ".{}", fifth_corner as u8)?;
+        }
+        Ok(())
+    }
+}
+
 fn take_stroke(chars: &mut Chars) -> Result<Stroke, ParseError> {
     let int: u8 = char_to_u8(chars.next().ok_or(ParseError::ToFewCharacters)?)?;
     let stroke = Stroke::try_from(int)?;
@@ -123,3 +149,16 @@ fn char_to_u8(c: char) -> Result<u8, ParseError> {
         _ => Err(ParseError::Digit),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::FourCorner;
+
+    #[test]
+    fn four_corner_round_trip() {
+        for text in ["1010.6", "4480"] {
+            let parsed: FourCorner = text.parse().unwrap();
+            assert_eq!(parsed.to_string(), text);
+        }
+    }
+}
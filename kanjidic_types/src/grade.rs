@@ -14,3 +14,15 @@ pub enum Grade {
     /// A Jinmeiyou kanji that is a variant of a Jouyou kanji.
     JinmeiyouJouyouVariant,
 }
+
+impl Grade {
+    /// The number used for the grade in KANJIDIC2.
+    pub fn level(&self) -> u8 {
+        match self {
+            Grade::Kyouiku(level) => *level,
+            Grade::Jouyou => 8,
+            Grade::Jinmeiyou => 9,
+            Grade::JinmeiyouJouyouVariant => 10,
+        }
+    }
+}
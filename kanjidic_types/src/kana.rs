@@ -0,0 +1,226 @@
+/// Hepburn syllables and their hiragana, including the common
+/// alternatives from Nihon-shiki and Kunrei-shiki.
+const SYLLABLES: &[(&str, &str)] = &[
+    ("a", "あ"),
+    ("i", "い"),
+    ("u", "う"),
+    ("e", "え"),
+    ("o", "お"),
+    ("ka", "か"),
+    ("ki", "き"),
+    ("ku", "く"),
+    ("ke", "け"),
+    ("ko", "こ"),
+    ("kya", "きゃ"),
+    ("kyu", "きゅ"),
+    ("kyo", "きょ"),
+    ("ga", "が"),
+    ("gi", "ぎ"),
+    ("gu", "ぐ"),
+    ("ge", "げ"),
+    ("go", "ご"),
+    ("gya", "ぎゃ"),
+    ("gyu", "ぎゅ"),
+    ("gyo", "ぎょ"),
+    ("sa", "さ"),
+    ("shi", "し"),
+    ("si", "し"),
+    ("su", "す"),
+    ("se", "せ"),
+    ("so", "そ"),
+    ("sha", "しゃ"),
+    ("shu", "しゅ"),
+    ("sho", "しょ"),
+    ("sya", "しゃ"),
+    ("syu", "しゅ"),
+    ("syo", "しょ"),
+    ("za", "ざ"),
+    ("ji", "じ"),
+    ("zi", "じ"),
+    ("zu", "ず"),
+    ("ze", "ぜ"),
+    ("zo", "ぞ"),
+    ("ja", "じゃ"),
+    ("ju", "じゅ"),
+    ("jo", "じょ"),
+    ("zya", "じゃ"),
+    ("zyu", "じゅ"),
+    ("zyo", "じょ"),
+    ("ta", "た"),
+    ("chi", "ち"),
+    ("ti", "ち"),
+    ("tsu", "つ"),
+    ("tu", "つ"),
+    ("te", "て"),
+    ("to", "と"),
+    ("cha", "ちゃ"),
+    ("chu", "ちゅ"),
+    ("cho", "ちょ"),
+    ("tya", "ちゃ"),
+    ("tyu", "ちゅ"),
+    ("tyo", "ちょ"),
+    ("da", "だ"),
+    ("di", "ぢ"),
+    ("du", "づ"),
+    ("dzu", "づ"),
+    ("de", "で"),
+    ("do", "ど"),
+    ("na", "な"),
+    ("ni", "に"),
+    ("nu", "ぬ"),
+    ("ne", "ね"),
+    ("no", "の"),
+    ("nya", "にゃ"),
+    ("nyu", "にゅ"),
+    ("nyo", "にょ"),
+    ("ha", "は"),
+    ("hi", "ひ"),
+    ("fu", "ふ"),
+    ("hu", "ふ"),
+    ("he", "へ"),
+    ("ho", "ほ"),
+    ("hya", "ひゃ"),
+    ("hyu", "ひゅ"),
+    ("hyo", "ひょ"),
+    ("ba", "ば"),
+    ("bi", "び"),
+    ("bu", "ぶ"),
+    ("be", "べ"),
+    ("bo", "ぼ"),
+    ("bya", "びゃ"),
+    ("byu", "びゅ"),
+    ("byo", "びょ"),
+    ("pa", "ぱ"),
+    ("pi", "ぴ"),
+    ("pu", "ぷ"),
+    ("pe", "ぺ"),
+    ("po", "ぽ"),
+    ("pya", "ぴゃ"),
+    ("pyu", "ぴゅ"),
+    ("pyo", "ぴょ"),
+    ("ma", "ま"),
+    ("mi", "み"),
+    ("mu", "む"),
+    ("me", "め"),
+    ("mo", "も"),
+    ("mya", "みゃ"),
+    ("myu", "みゅ"),
+    ("myo", "みょ"),
+    ("ya", "や"),
+    ("yu", "ゆ"),
+    ("yo", "よ"),
+    ("ra", "ら"),
+    ("ri", "り"),
+    ("ru", "る"),
+    ("re", "れ"),
+    ("ro", "ろ"),
+    ("rya", "りゃ"),
+    ("ryu", "りゅ"),
+    ("ryo", "りょ"),
+    ("wa", "わ"),
+    ("wo", "を"),
+];
+
+/// Converts katakana to hiragana, leaving other characters alone.
+pub fn to_hiragana(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            'ァ'..='ヶ' => std::char::from_u32(c as u32 - 0x60).unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Converts Hepburn romaji to hiragana.
+/// Long vowels may be written with macrons or circumflexes,
+/// and a syllabic n may be followed by an apostrophe to separate it
+/// from a following vowel. Returns `None` for text that is not romaji.
+pub fn romaji_to_hiragana(text: &str) -> Option<String> {
+    let text: String = text
+        .to_lowercase()
+        .chars()
+        .flat_map(|c| {
+            let long = match c {
+                'ā' | 'â' => "aa",
+                'ī' | 'î' => "ii",
+                'ū' | 'û' => "uu",
+                'ē' | 'ê' => "ee",
+                'ō' | 'ô' => "ou",
+                _ => "",
+            };
+            if long.is_empty() {
+                vec![c]
+            } else {
+                long.chars().collect()
+            }
+        })
+        .collect();
+    if !text.is_ascii() {
+        return None;
+    }
+    let bytes = text.as_bytes();
+    let is_vowel = |b: u8| b"aiueo".contains(&b);
+    let mut out = String::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        let next = bytes.get(i + 1).copied();
+        if c == b'n' && next.is_none_or(|next| !is_vowel(next) && next != b'y') {
+            out.push('ん');
+            i += match next {
+                Some(b'\'') => 2,
+                Some(b'n') if bytes.get(i + 2).is_none_or(|&b| !is_vowel(b) && b != b'y') => 2,
+                _ => 1,
+            };
+            continue;
+        }
+        let doubled = Some(c) == next || (c == b't' && next == Some(b'c'));
+        if doubled && !is_vowel(c) && c != b'n' {
+            out.push('っ');
+            i += 1;
+            continue;
+        }
+        let (romaji, kana) = (1..=3)
+            .rev()
+            .filter_map(|len| text.get(i..i + len))
+            .find_map(|romaji| SYLLABLES.iter().find(|(syllable, _)| *syllable == romaji))?;
+        out.push_str(kana);
+        i += romaji.len();
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{romaji_to_hiragana, to_hiragana};
+
+    #[test]
+    fn hiragana() {
+        assert_eq!(to_hiragana("アク"), "あく");
+        assert_eq!(to_hiragana("つ.ぐ"), "つ.ぐ");
+        assert_eq!(to_hiragana("ヴァ"), "ゔぁ");
+    }
+
+    #[test]
+    fn romaji() {
+        let cases = [
+            ("a", "あ"),
+            ("Tsugu", "つぐ"),
+            ("kou", "こう"),
+            ("kō", "こう"),
+            ("gakkou", "がっこう"),
+            ("matcha", "まっちゃ"),
+            ("shinbun", "しんぶん"),
+            ("kan'i", "かんい"),
+            ("konnichi", "こんにち"),
+            ("shinn", "しん"),
+            ("kyuu", "きゅう"),
+            ("ji", "じ"),
+        ];
+        for (romaji, hiragana) in cases.iter() {
+            assert_eq!(romaji_to_hiragana(romaji).as_deref(), Some(*hiragana));
+        }
+        assert_eq!(romaji_to_hiragana("xyz"), None);
+        assert_eq!(romaji_to_hiragana("あ"), None);
+    }
+}
@@ -1,5 +1,6 @@
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde_repr::*;
+use std::convert::TryFrom;
 
 #[derive(
     Debug,
@@ -232,3 +233,336 @@ pub enum KangXi {
     Turtle,
     Flute,
 }
+
+/// The Kangxi Radicals block character, CJK unified ideograph, stroke count,
+/// common variant forms and Japanese names of a radical.
+type Info = (char, char, u8, &'static [char], &'static [&'static str]);
+
+/// The details of each radical, in order of radical number.
+const RADICALS: [Info; 214] = [
+    ('⼀', '一', 1, &[], &["いち"]),
+    ('⼁', '丨', 1, &[], &["ぼう", "たてぼう"]),
+    ('⼂', '丶', 1, &[], &["てん"]),
+    ('⼃', '丿', 1, &[], &["の", "はらいぼう"]),
+    ('⼄', '乙', 1, &['乚'], &["おつ", "おつにょう"]),
+    ('⼅', '亅', 1, &[], &["はねぼう"]),
+    ('⼆', '二', 2, &[], &["に"]),
+    ('⼇', '亠', 2, &[], &["なべぶた", "けいさんかんむり"]),
+    ('⼈', '人', 2, &['亻'], &["ひと", "にんべん", "ひとやね"]),
+    ('⼉', '儿', 2, &[], &["ひとあし", "にんにょう"]),
+    ('⼊', '入', 2, &[], &["いる", "いりがしら"]),
+    ('⼋', '八', 2, &['丷'], &["はち", "はちがしら"]),
+    (
+        '⼌',
+        '冂',
+        2,
+        &[],
+        &["けいがまえ", "まきがまえ", "どうがまえ"],
+    ),
+    ('⼍', '冖', 2, &[], &["わかんむり"]),
+    ('⼎', '冫', 2, &[], &["にすい"]),
+    ('⼏', '几', 2, &[], &["つくえ", "かぜかんむり"]),
+    ('⼐', '凵', 2, &[], &["うけばこ", "かんにょう"]),
+    ('⼑', '刀', 2, &['刂'], &["かたな", "りっとう"]),
+    ('⼒', '力', 2, &[], &["ちから"]),
+    ('⼓', '勹', 2, &[], &["つつみがまえ"]),
+    ('⼔', '匕', 2, &[], &["さじ", "ひ"]),
+    ('⼕', '匚', 2, &[], &["はこがまえ"]),
+    ('⼖', '匸', 2, &[], &["かくしがまえ"]),
+    ('⼗', '十', 2, &[], &["じゅう"]),
+    ('⼘', '卜', 2, &[], &["ぼく", "と"]),
+    ('⼙', '卩', 2, &['㔾'], &["ふしづくり", "わりふ"]),
+    ('⼚', '厂', 2, &[], &["がんだれ"]),
+    ('⼛', '厶', 2, &[], &["む"]),
+    ('⼜', '又', 2, &[], &["また"]),
+    ('⼝', '口', 3, &[], &["くち", "くちへん"]),
+    ('⼞', '囗', 3, &[], &["くにがまえ"]),
+    ('⼟', '土', 3, &[], &["つち", "つちへん"]),
+    ('⼠', '士', 3, &[], &["さむらい"]),
+    ('⼡', '夂', 3, &[], &["ふゆがしら"]),
+    ('⼢', '夊', 3, &[], &["すいにょう"]),
+    ('⼣', '夕', 3, &[], &["ゆう", "ゆうべ"]),
+    ('⼤', '大', 3, &[], &["だい"]),
+    ('⼥', '女', 3, &[], &["おんな", "おんなへん"]),
+    ('⼦', '子', 3, &[], &["こ", "こへん"]),
+    ('⼧', '宀', 3, &[], &["うかんむり"]),
+    ('⼨', '寸', 3, &[], &["すん"]),
+    ('⼩', '小', 3, &[], &["しょう", "なおがしら"]),
+    ('⼪', '尢', 3, &['尣'], &["だいのまげあし"]),
+    ('⼫', '尸', 3, &[], &["しかばね"]),
+    ('⼬', '屮', 3, &[], &["てつ"]),
+    ('⼭', '山', 3, &[], &["やま", "やまへん"]),
+    ('⼮', '巛', 3, &['川'], &["かわ", "まがりがわ"]),
+    ('⼯', '工', 3, &[], &["たくみ", "こう"]),
+    ('⼰', '己', 3, &[], &["おのれ"]),
+    ('⼱', '巾', 3, &[], &["はば", "きんべん"]),
+    ('⼲', '干', 3, &[], &["かん", "いちじゅう"]),
+    ('⼳', '幺', 3, &[], &["いとがしら", "よう"]),
+    ('⼴', '广', 3, &[], &["まだれ"]),
+    ('⼵', '廴', 3, &[], &["えんにょう"]),
+    ('⼶', '廾', 3, &[], &["にじゅうあし"]),
+    ('⼷', '弋', 3, &[], &["しきがまえ"]),
+    ('⼸', '弓', 3, &[], &["ゆみ", "ゆみへん"]),
+    ('⼹', '彐', 3, &['彑'], &["けいがしら"]),
+    ('⼺', '彡', 3, &[], &["さんづくり"]),
+    ('⼻', '彳', 3, &[], &["ぎょうにんべん"]),
+    (
+        '⼼',
+        '心',
+        4,
+        &['忄'],
+        &["こころ", "りっしんべん", "したごころ"],
+    ),
+    ('⼽', '戈', 4, &[], &["ほこづくり", "ほこがまえ"]),
+    (
+        '⼾',
+        '戶',
+        4,
+        &['户', '戸'],
+        &["と", "とかんむり", "とだれ"],
+    ),
+    ('⼿', '手', 4, &['扌'], &["て", "てへん"]),
+    ('⽀', '支', 4, &[], &["しにょう", "じゅうまた"]),
+    ('⽁', '攴', 4, &['攵'], &["ぼくにょう", "のぶん"]),
+    ('⽂', '文', 4, &[], &["ぶん"]),
+    ('⽃', '斗', 4, &[], &["とます"]),
+    ('⽄', '斤', 4, &[], &["おのづくり"]),
+    ('⽅', '方', 4, &[], &["ほう", "かたへん"]),
+    ('⽆', '无', 4, &['旡'], &["むにょう", "すでのつくり"]),
+    ('⽇', '日', 4, &[], &["ひ", "にち", "ひへん"]),
+    ('⽈', '曰', 4, &[], &["ひらび", "いわく"]),
+    ('⽉', '月', 4, &[], &["つき", "つきへん"]),
+    ('⽊', '木', 4, &[], &["き", "きへん"]),
+    ('⽋', '欠', 4, &[], &["あくび", "かける"]),
+    ('⽌', '止', 4, &[], &["とめる", "とめへん"]),
+    ('⽍', '歹', 4, &['歺'], &["がつへん", "いちたへん"]),
+    ('⽎', '殳', 4, &[], &["るまた", "ほこづくり"]),
+    ('⽏', '毋', 4, &['母'], &["なかれ"]),
+    ('⽐', '比', 4, &[], &["くらべる", "ならびひ"]),
+    ('⽑', '毛', 4, &[], &["け"]),
+    ('⽒', '氏', 4, &[], &["うじ"]),
+    ('⽓', '气', 4, &[], &["きがまえ"]),
+    (
+        '⽔',
+        '水',
+        4,
+        &['氵', '氺'],
+        &["みず", "さんずい", "したみず"],
+    ),
+    (
+        '⽕',
+        '火',
+        4,
+        &['灬'],
+        &["ひ", "ひへん", "れっか", "れんが"],
+    ),
+    ('⽖', '爪', 4, &['爫'], &["つめ", "つめかんむり"]),
+    ('⽗', '父', 4, &[], &["ちち"]),
+    ('⽘', '爻', 4, &[], &["こう", "めめ"]),
+    ('⽙', '爿', 4, &['丬'], &["しょうへん"]),
+    ('⽚', '片', 4, &[], &["かた", "かたへん"]),
+    ('⽛', '牙', 4, &[], &["きば"]),
+    ('⽜', '牛', 4, &['牜'], &["うし", "うしへん"]),
+    ('⽝', '犬', 4, &['犭'], &["いぬ", "けものへん"]),
+    ('⽞', '玄', 5, &[], &["げん"]),
+    ('⽟', '玉', 5, &['王'], &["たま", "おうへん", "たまへん"]),
+    ('⽠', '瓜', 5, &[], &["うり"]),
+    ('⽡', '瓦', 5, &[], &["かわら"]),
+    ('⽢', '甘', 5, &[], &["かん", "あまい"]),
+    ('⽣', '生', 5, &[], &["せい", "うまれる"]),
+    ('⽤', '用', 5, &[], &["もちいる"]),
+    ('⽥', '田', 5, &[], &["た", "たへん"]),
+    ('⽦', '疋', 5, &['𤴔'], &["ひき", "ひきへん"]),
+    ('⽧', '疒', 5, &[], &["やまいだれ"]),
+    ('⽨', '癶', 5, &[], &["はつがしら"]),
+    ('⽩', '白', 5, &[], &["しろ"]),
+    ('⽪', '皮', 5, &[], &["けがわ"]),
+    ('⽫', '皿', 5, &[], &["さら"]),
+    ('⽬', '目', 5, &[], &["め", "めへん"]),
+    ('⽭', '矛', 5, &[], &["ほこ"]),
+    ('⽮', '矢', 5, &[], &["や", "やへん"]),
+    ('⽯', '石', 5, &[], &["いし", "いしへん"]),
+    ('⽰', '示', 5, &['礻'], &["しめす", "しめすへん"]),
+    ('⽱', '禸', 5, &[], &["ぐうのあし"]),
+    ('⽲', '禾', 5, &[], &["のぎ", "のぎへん"]),
+    ('⽳', '穴', 5, &[], &["あな", "あなかんむり"]),
+    ('⽴', '立', 5, &[], &["たつ", "たつへん"]),
+    ('⽵', '竹', 6, &['𥫗'], &["たけ", "たけかんむり"]),
+    ('⽶', '米', 6, &[], &["こめ", "こめへん"]),
+    ('⽷', '糸', 6, &['糹', '纟'], &["いと", "いとへん"]),
+    ('⽸', '缶', 6, &[], &["ほとぎ"]),
+    ('⽹', '网', 6, &['罒', '罓'], &["あみがしら", "よこめ"]),
+    ('⽺', '羊', 6, &['𦍌'], &["ひつじ"]),
+    ('⽻', '羽', 6, &[], &["はね"]),
+    ('⽼', '老', 6, &['耂'], &["おいかんむり"]),
+    ('⽽', '而', 6, &[], &["しかして"]),
+    ('⽾', '耒', 6, &[], &["らいすき"]),
+    ('⽿', '耳', 6, &[], &["みみ", "みみへん"]),
+    ('⾀', '聿', 6, &['肀'], &["ふでづくり"]),
+    ('⾁', '肉', 6, &['月'], &["にく", "にくづき"]),
+    ('⾂', '臣', 6, &[], &["しん"]),
+    ('⾃', '自', 6, &[], &["みずから"]),
+    ('⾄', '至', 6, &[], &["いたる"]),
+    ('⾅', '臼', 6, &[], &["うす"]),
+    ('⾆', '舌', 6, &[], &["した"]),
+    ('⾇', '舛', 6, &[], &["まいあし"]),
+    ('⾈', '舟', 6, &[], &["ふね", "ふねへん"]),
+    ('⾉', '艮', 6, &[], &["こんづくり", "ねづくり"]),
+    ('⾊', '色', 6, &[], &["いろ"]),
+    ('⾋', '艸', 6, &['艹'], &["くさ", "くさかんむり"]),
+    ('⾌', '虍', 6, &[], &["とらかんむり", "とらがしら"]),
+    ('⾍', '虫', 6, &[], &["むし", "むしへん"]),
+    ('⾎', '血', 6, &[], &["ち"]),
+    ('⾏', '行', 6, &[], &["ぎょうがまえ", "ゆきがまえ"]),
+    ('⾐', '衣', 6, &['衤'], &["ころも", "ころもへん"]),
+    ('⾑', '襾', 6, &['覀', '西'], &["にし", "おおいかんむり"]),
+    ('⾒', '見', 7, &['见'], &["みる"]),
+    ('⾓', '角', 7, &[], &["つの", "つのへん"]),
+    ('⾔', '言', 7, &['訁', '讠'], &["げん", "ごんべん"]),
+    ('⾕', '谷', 7, &[], &["たに"]),
+    ('⾖', '豆', 7, &[], &["まめ"]),
+    ('⾗', '豕', 7, &[], &["いのこ", "ぶた"]),
+    ('⾘', '豸', 7, &[], &["むじなへん"]),
+    ('⾙', '貝', 7, &['贝'], &["かい", "かいへん"]),
+    ('⾚', '赤', 7, &[], &["あか"]),
+    ('⾛', '走', 7, &[], &["はしる", "そうにょう"]),
+    ('⾜', '足', 7, &['𧾷'], &["あし", "あしへん"]),
+    ('⾝', '身', 7, &[], &["み"]),
+    ('⾞', '車', 7, &['车'], &["くるま", "くるまへん"]),
+    ('⾟', '辛', 7, &[], &["からい"]),
+    ('⾠', '辰', 7, &[], &["しんのたつ"]),
+    ('⾡', '辵', 7, &['辶'], &["しんにょう", "しんにゅう"]),
+    ('⾢', '邑', 7, &['阝'], &["むら", "おおざと"]),
+    ('⾣', '酉', 7, &[], &["ひよみのとり", "とりへん"]),
+    ('⾤', '釆', 7, &[], &["のごめ"]),
+    ('⾥', '里', 7, &[], &["さと"]),
+    ('⾦', '金', 8, &['釒', '钅'], &["かね", "かねへん"]),
+    ('⾧', '長', 8, &['镸', '长'], &["ながい"]),
+    ('⾨', '門', 8, &['门'], &["もん", "もんがまえ"]),
+    ('⾩', '阜', 8, &['阝'], &["おか", "こざとへん"]),
+    ('⾪', '隶', 8, &[], &["れいづくり"]),
+    ('⾫', '隹', 8, &[], &["ふるとり"]),
+    ('⾬', '雨', 8, &[], &["あめ", "あめかんむり"]),
+    ('⾭', '靑', 8, &['青'], &["あお"]),
+    ('⾮', '非', 8, &[], &["あらず"]),
+    ('⾯', '面', 9, &[], &["めん"]),
+    ('⾰', '革', 9, &[], &["かくのかわ", "つくりがわ"]),
+    ('⾱', '韋', 9, &['韦'], &["なめしがわ"]),
+    ('⾲', '韭', 9, &[], &["にら"]),
+    ('⾳', '音', 9, &[], &["おと"]),
+    ('⾴', '頁', 9, &['页'], &["おおがい"]),
+    ('⾵', '風', 9, &['风'], &["かぜ"]),
+    ('⾶', '飛', 9, &['飞'], &["とぶ"]),
+    ('⾷', '食', 9, &['飠', '饣'], &["しょく", "しょくへん"]),
+    ('⾸', '首', 9, &[], &["くび"]),
+    ('⾹', '香', 9, &[], &["かおり"]),
+    ('⾺', '馬', 10, &['马'], &["うま", "うまへん"]),
+    ('⾻', '骨', 10, &[], &["ほね", "ほねへん"]),
+    ('⾼', '高', 10, &[], &["たかい"]),
+    ('⾽', '髟', 10, &[], &["かみがしら"]),
+    ('⾾', '鬥', 10, &[], &["とうがまえ"]),
+    ('⾿', '鬯', 10, &[], &["ちょう", "においざけ"]),
+    ('⿀', '鬲', 10, &[], &["かなえ", "れき"]),
+    ('⿁', '鬼', 10, &[], &["おに"]),
+    ('⿂', '魚', 11, &['鱼'], &["うお", "うおへん"]),
+    ('⿃', '鳥', 11, &['鸟'], &["とり"]),
+    ('⿄', '鹵', 11, &['卤'], &["しお"]),
+    ('⿅', '鹿', 11, &[], &["しか"]),
+    ('⿆', '麥', 11, &['麦'], &["むぎ"]),
+    ('⿇', '麻', 11, &[], &["あさ"]),
+    ('⿈', '黃', 12, &['黄'], &["き"]),
+    ('⿉', '黍', 12, &[], &["きび"]),
+    ('⿊', '黑', 12, &['黒'], &["くろ"]),
+    ('⿋', '黹', 12, &[], &["ふつ"]),
+    ('⿌', '黽', 13, &['黾'], &["べん"]),
+    ('⿍', '鼎', 13, &[], &["かなえ"]),
+    ('⿎', '鼓', 13, &[], &["つづみ"]),
+    ('⿏', '鼠', 13, &[], &["ねずみ"]),
+    ('⿐', '鼻', 14, &[], &["はな"]),
+    ('⿑', '齊', 14, &['斉', '齐'], &["せい"]),
+    ('⿒', '齒', 15, &['歯', '齿'], &["は"]),
+    ('⿓', '龍', 16, &['竜', '龙'], &["りゅう"]),
+    ('⿔', '龜', 16, &['亀', '龟'], &["かめ"]),
+    ('⿕', '龠', 17, &[], &["やく"]),
+];
+
+impl KangXi {
+    fn info(self) -> &'static Info {
+        &RADICALS[self as usize - 1]
+    }
+
+    /// The character for the radical in the Kangxi Radicals block, such as ⽔.
+    pub fn glyph(self) -> char {
+        self.info().0
+    }
+
+    /// The equivalent CJK unified ideograph, such as 水.
+    /// Fonts are more likely to cover these than the Kangxi Radicals block.
+    pub fn ideograph(self) -> char {
+        self.info().1
+    }
+
+    /// The number of strokes in the radical's standard form.
+    pub fn stroke_count(self) -> u8 {
+        self.info().2
+    }
+
+    /// Common variant forms of the radical, such as 氵 for water
+    /// or simplified Chinese forms. Often empty.
+    pub fn variants(self) -> &'static [char] {
+        self.info().3
+    }
+
+    /// The Japanese names of the radical in hiragana, most common first.
+    /// Many radicals also have names for their forms in particular positions,
+    /// such as さんずい for 氵 on the left.
+    pub fn names(self) -> &'static [&'static str] {
+        self.info().4
+    }
+
+    /// Finds the radical written as the given glyph, ideograph or variant.
+    /// Glyphs and ideographs take precedence over variants, so 月 is the moon
+    /// rather than meat, and a variant shared between radicals, such as 阝,
+    /// belongs to the lower numbered one.
+    pub fn from_glyph(glyph: char) -> Option<Self> {
+        let number = RADICALS
+            .iter()
+            .position(|info| info.0 == glyph || info.1 == glyph)
+            .or_else(|| RADICALS.iter().position(|info| info.3.contains(&glyph)))?;
+        Self::try_from(number as u8 + 1).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KangXi;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn details() {
+        let water = KangXi::Water;
+        assert_eq!(water.glyph(), '⽔');
+        assert_eq!(water.ideograph(), '水');
+        assert_eq!(water.stroke_count(), 4);
+        assert_eq!(water.variants(), &['氵', '氺']);
+        assert!(water.names().contains(&"さんずい"));
+        assert_eq!(KangXi::One.glyph(), '⼀');
+        assert_eq!(KangXi::Flute.glyph(), '⿕');
+        assert_eq!(KangXi::Flute.stroke_count(), 17);
+    }
+
+    #[test]
+    fn finds_glyphs() {
+        assert_eq!(KangXi::from_glyph('⽔'), Some(KangXi::Water));
+        assert_eq!(KangXi::from_glyph('水'), Some(KangXi::Water));
+        assert_eq!(KangXi::from_glyph('氵'), Some(KangXi::Water));
+        assert_eq!(KangXi::from_glyph('扌'), Some(KangXi::Hand));
+        assert_eq!(KangXi::from_glyph('月'), Some(KangXi::Moon));
+        assert_eq!(KangXi::from_glyph('亜'), None);
+        for number in 1..=214u8 {
+            let radical = KangXi::try_from(number).unwrap();
+            assert_eq!(KangXi::from_glyph(radical.glyph()), Some(radical));
+            assert_eq!(KangXi::from_glyph(radical.ideograph()), Some(radical));
+        }
+    }
+}
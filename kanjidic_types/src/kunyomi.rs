@@ -7,7 +7,7 @@ use nom::{
     sequence::tuple,
 };
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fmt, str::FromStr};
 use thiserror::Error;
 
 /// A kunyomi kanji reading.
@@ -21,6 +21,8 @@ pub struct Kunyomi {
     pub okurigana: Option<String>,
     /// Whether the reading is as a prefix or suffix.
     pub kind: KunyomiKind,
+    /// Whether the reading is approved for use in the jouyou kanji list
+    pub jouyou: bool,
 }
 
 /// The kind of kunyomi reading.
@@ -72,10 +74,35 @@ impl TryFrom<&str> for Kunyomi {
             reading,
             okurigana,
             kind,
+            jouyou: false,
         })
     }
 }
 
+impl FromStr for Kunyomi {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for Kunyomi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.kind == KunyomiKind::Suffix {
+            f.write_str("-")?;
+        }
+        f.write_str(&self.reading)?;
+        if let Some(okurigana) = &self.okurigana {
+            write!(f, ".{}", okurigana)?;
+        }
+        if self.kind == KunyomiKind::Prefix {
+            f.write_str("-")?;
+        }
+        Ok(())
+    }
+}
+
 fn parts(s: &str) -> IResult<(bool, Vec<String>, bool)> {
     tuple((fix, okurigana, fix))(s)
 }
@@ -87,3 +114,16 @@ fn okurigana(s: &str) -> IResult<Vec<String>> {
 fn fix(s: &str) -> IResult<bool> {
     map(opt(char('-')), |c| c.is_some())(s)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Kunyomi;
+
+    #[test]
+    fn kunyomi_round_trip() {
+        for text in ["おし", "つ.ぐ", "-か", "あ-"] {
+            let parsed: Kunyomi = text.parse().unwrap();
+            assert_eq!(parsed.to_string(), text);
+        }
+    }
+}
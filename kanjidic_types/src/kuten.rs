@@ -2,7 +2,7 @@ use crate::{shared::NomErrorReason, take_uint, IResult, NomErr};
 use nom::character::complete::char;
 use nom::sequence::tuple;
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fmt, str::FromStr};
 use thiserror::Error;
 
 /// A kuten representation of a JIS X 0213 character.
@@ -40,6 +40,33 @@ impl TryFrom<&str> for Kuten {
     }
 }
 
+impl FromStr for Kuten {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for Kuten {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{:02}-{:02}", self.plane, self.ku, self.ten)
+    }
+}
+
 fn kuten_parts(s: &str) -> IResult<(u8, char, u8, char, u8)> {
     tuple((take_uint, char('-'), take_uint, char('-'), take_uint))(s)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Kuten;
+
+    #[test]
+    fn kuten_round_trip() {
+        for text in ["1-16-01", "1-48-19", "2-94-94"] {
+            let parsed: Kuten = text.parse().unwrap();
+            assert_eq!(parsed.to_string(), text);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt,
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+use thiserror::Error;
+
+/// The language of a kanji meaning, identified by ISO 639-1 code.
+/// Languages compare and sort by code.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Language {
+    /// English, the default when a meaning has no m_lang attribute
+    #[default]
+    En,
+    /// French
+    Fr,
+    /// Spanish
+    Es,
+    /// Portuguese
+    Pt,
+    /// A language not known at the time of writing
+    Other(Code),
+}
+
+/// Two lowercase ASCII letters, as checked by `TryFrom<&str>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Code([u8; 2]);
+
+impl Code {
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.0).expect("Codes hold ASCII letters")
+    }
+}
+
+impl TryFrom<&str> for Code {
+    type Error = ParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        match *text.as_bytes() {
+            [a, b] if a.is_ascii_lowercase() && b.is_ascii_lowercase() => Ok(Code([a, b])),
+            _ => Err(ParseError::Code(text.to_owned())),
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    #[error("(Language) Expected a two-letter ISO 639-1 code: {0}")]
+    Code(String),
+}
+
+impl Language {
+    /// The ISO 639-1 code used for the m_lang attribute in KANJIDIC2.
+    pub fn code(&self) -> &str {
+        match self {
+            Language::En => "en",
+            Language::Fr => "fr",
+            Language::Es => "es",
+            Language::Pt => "pt",
+            Language::Other(code) => code.as_str(),
+        }
+    }
+}
+
+impl TryFrom<&str> for Language {
+    type Error = ParseError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        let code = text.to_ascii_lowercase();
+        match code.as_str() {
+            "en" => Ok(Language::En),
+            "fr" => Ok(Language::Fr),
+            "es" => Ok(Language::Es),
+            "pt" => Ok(Language::Pt),
+            _ => Code::try_from(code.as_str())
+                .map(Language::Other)
+                .map_err(|_| ParseError::Code(text.to_owned())),
+        }
+    }
+}
+
+impl TryFrom<String> for Language {
+    type Error = ParseError;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        Self::try_from(text.as_str())
+    }
+}
+
+impl FromStr for Language {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl From<Language> for String {
+    fn from(language: Language) -> Self {
+        language.code().to_owned()
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl PartialEq for Language {
+    fn eq(&self, other: &Self) -> bool {
+        self.code() == other.code()
+    }
+}
+
+impl Eq for Language {}
+
+impl Hash for Language {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code().hash(state);
+    }
+}
+
+impl PartialOrd for Language {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Language {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.code().cmp(other.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Code, Language, ParseError};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn language_round_trip() {
+        for text in ["en", "fr", "es", "pt", "de"] {
+            let parsed: Language = text.parse().unwrap();
+            assert_eq!(parsed.to_string(), text);
+        }
+        assert_eq!("FR".parse(), Ok(Language::Fr));
+        assert_eq!(
+            "de".parse(),
+            Ok(Language::Other(Code::try_from("de").unwrap()))
+        );
+        assert!(Code::try_from("DE").is_err());
+        assert_eq!(
+            "eng".parse::<Language>(),
+            Err(ParseError::Code("eng".into()))
+        );
+        assert!("e1".parse::<Language>().is_err());
+    }
+
+    #[test]
+    fn sorts_by_code() {
+        let mut languages = [
+            Language::Pt,
+            Language::Other(Code::try_from("de").unwrap()),
+            Language::En,
+            Language::Es,
+        ];
+        languages.sort();
+        let codes: Vec<_> = languages.iter().map(Language::code).collect();
+        assert_eq!(codes, vec!["de", "en", "es", "pt"]);
+    }
+}
@@ -7,15 +7,34 @@ pub use character::Character;
 pub mod codepoint;
 pub use codepoint::Codepoint;
 
+pub mod comparison;
+pub use comparison::Comparison;
+
+pub mod database_version;
+pub use database_version::DatabaseVersion;
+
+#[cfg(feature = "delta")]
+pub mod delta;
+#[cfg(feature = "delta")]
+pub use delta::Delta;
+
 pub mod de_roo;
 pub use de_roo::DeRoo;
 
+pub mod field;
+pub use field::Field;
+
+pub mod filter;
+pub use filter::Filter;
+
 pub mod four_corner;
 pub use four_corner::FourCorner;
 
 pub mod grade;
 pub use grade::Grade;
 
+pub mod kana;
+
 pub mod kangxi;
 pub use kangxi::KangXi;
 
@@ -25,12 +44,21 @@ pub use kunyomi::Kunyomi;
 pub mod kuten;
 pub use kuten::Kuten;
 
+pub mod language;
+pub use language::Language;
+
+pub mod meaning_index;
+pub use meaning_index::MeaningIndex;
+
 pub mod moro;
 pub use moro::Moro;
 
 pub mod oneill;
 pub use oneill::Oneill;
 
+pub mod onyomi;
+pub use onyomi::Onyomi;
+
 pub mod pin_yin;
 pub use pin_yin::PinYin;
 
@@ -43,12 +71,26 @@ pub use radical::Radical;
 pub mod reading;
 pub use reading::Reading;
 
+pub mod reading_index;
+pub use reading_index::ReadingIndex;
+
 pub mod reference;
 pub use reference::Reference;
 
+pub mod reference_index;
+pub use reference_index::ReferenceIndex;
+
+pub mod sino_index;
+pub use sino_index::SinoIndex;
+
 pub mod skip;
 pub use skip::Skip;
 
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
+#[cfg(feature = "snapshot")]
+pub use snapshot::Snapshot;
+
 pub mod spahn_hadamitzky;
 pub use spahn_hadamitzky::{Descriptor as ShDesc, Radical as ShRadical};
 
@@ -0,0 +1,214 @@
+use crate::{Character, Language};
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BTreeSet, HashMap},
+};
+
+/// How closely a meaning matches a search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Relevance {
+    /// The last word of the search only begins a word of the meaning.
+    Prefix,
+    /// Every word of the search is a word of the meaning.
+    Word,
+    /// The meaning is the same as the search.
+    Exact,
+}
+
+/// A kanji matching a search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Hit {
+    /// The matching kanji.
+    pub literal: char,
+    /// The best match among the kanji's meanings.
+    pub relevance: Relevance,
+}
+
+/// One meaning of a kanji in one language.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Entry {
+    literal: char,
+    language: Language,
+    /// The words of the meaning, separated by single spaces.
+    words: String,
+    frequency: Option<u16>,
+}
+
+/// An inverted index from the words of kanji meanings to the kanji.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeaningIndex {
+    entries: Vec<Entry>,
+    /// Positions in `entries` of the meanings containing each word, in order.
+    words: BTreeMap<String, Vec<u32>>,
+}
+
+impl MeaningIndex {
+    /// Indexes the meanings of the given characters in every language.
+    pub fn new(characters: &[Character]) -> Self {
+        let mut out = Self::default();
+        for character in characters {
+            for (language, meanings) in character.translations() {
+                for meaning in meanings {
+                    let words = tokenize(&meaning);
+                    if words.is_empty() {
+                        continue;
+                    }
+                    let position = out.entries.len() as u32;
+                    for word in words.iter() {
+                        let positions = out.words.entry(word.clone()).or_default();
+                        if positions.last() != Some(&position) {
+                            positions.push(position);
+                        }
+                    }
+                    out.entries.push(Entry {
+                        literal: character.literal,
+                        language,
+                        words: words.join(" "),
+                        frequency: character.frequency,
+                    });
+                }
+            }
+        }
+        out
+    }
+
+    /// Finds kanji with a meaning containing every word of the query,
+    /// allowing the last word to be the start of a longer word.
+    /// Only meanings in the given languages are searched, or all of them if empty.
+    ///
+    /// Hits are ordered by relevance, then by frequency,
+    /// with kanji lacking a frequency ranking last.
+    pub fn search(&self, query: &str, languages: &[Language]) -> Vec<Hit> {
+        let words = tokenize(query);
+        let (last, rest) = match words.split_last() {
+            Some(split) => split,
+            None => return vec![],
+        };
+        let mut candidates: BTreeSet<u32> = self
+            .words
+            .range(last.clone()..)
+            .take_while(|(word, _)| word.starts_with(last.as_str()))
+            .flat_map(|(_, positions)| positions.iter().copied())
+            .collect();
+        for word in rest {
+            let positions = self.words.get(word).map_or(&[][..], Vec::as_slice);
+            candidates.retain(|position| positions.binary_search(position).is_ok());
+        }
+
+        let query = words.join(" ");
+        let mut best: HashMap<char, (Relevance, Option<u16>)> = HashMap::new();
+        for position in candidates {
+            let entry = &self.entries[position as usize];
+            if !languages.is_empty() && !languages.contains(&entry.language) {
+                continue;
+            }
+            let relevance = if entry.words == query {
+                Relevance::Exact
+            } else if words
+                .iter()
+                .all(|word| entry.words.split(' ').any(|w| w == word))
+            {
+                Relevance::Word
+            } else {
+                Relevance::Prefix
+            };
+            let current = best
+                .entry(entry.literal)
+                .or_insert((relevance, entry.frequency));
+            current.0 = current.0.max(relevance);
+        }
+
+        let mut hits: Vec<_> = best.into_iter().collect();
+        hits.sort_by_key(|(literal, (relevance, frequency))| {
+            (Reverse(*relevance), frequency.unwrap_or(u16::MAX), *literal)
+        });
+        hits.into_iter()
+            .map(|(literal, (relevance, _))| Hit { literal, relevance })
+            .collect()
+    }
+}
+
+/// Splits text into lowercase words, dropping punctuation.
+pub fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tokenize, Hit, MeaningIndex, Relevance};
+    use crate::{character::ReadingMeaningGroup, Character, Language};
+
+    fn index() -> MeaningIndex {
+        MeaningIndex::new(&[
+            Character {
+                frequency: Some(1509),
+                reading_meaning_groups: vec![ReadingMeaningGroup::test(
+                    vec![],
+                    &[(Language::En, "Asia"), (Language::En, "rank next")],
+                )],
+                ..Character::test('亜')
+            },
+            Character {
+                frequency: Some(408),
+                reading_meaning_groups: vec![ReadingMeaningGroup::test(
+                    vec![],
+                    &[(Language::En, "next"), (Language::Fr, "suivant")],
+                )],
+                ..Character::test('次')
+            },
+            Character {
+                reading_meaning_groups: vec![ReadingMeaningGroup::test(
+                    vec![],
+                    &[(Language::En, "continent"), (Language::En, "Asian sandbar")],
+                )],
+                ..Character::test('洲')
+            },
+        ])
+    }
+
+    fn literals(hits: &[Hit]) -> Vec<char> {
+        hits.iter().map(|hit| hit.literal).collect()
+    }
+
+    #[test]
+    fn tokenizes() {
+        assert_eq!(
+            tokenize("  (Asia,) rank-next "),
+            vec!["asia", "rank", "next"]
+        );
+    }
+
+    #[test]
+    fn ranks_hits() {
+        let index = index();
+        assert_eq!(literals(&index.search("next", &[])), vec!['次', '亜']);
+        assert_eq!(index.search("next", &[])[1].relevance, Relevance::Word);
+        assert_eq!(literals(&index.search("Rank next", &[])), vec!['亜']);
+        assert_eq!(
+            index.search("rank next", &[])[0].relevance,
+            Relevance::Exact
+        );
+        let asia = index.search("asia", &[]);
+        assert_eq!(literals(&asia), vec!['亜', '洲']);
+        assert_eq!(asia[1].relevance, Relevance::Prefix);
+        assert_eq!(literals(&index.search("asian sand", &[])), vec!['洲']);
+        assert!(index.search("sand asian", &[]).is_empty());
+        assert!(index.search("?!", &[]).is_empty());
+    }
+
+    #[test]
+    fn filters_languages() {
+        let index = index();
+        assert_eq!(
+            literals(&index.search("suivant", &[Language::Fr])),
+            vec!['次']
+        );
+        assert!(index.search("next", &[Language::Fr]).is_empty());
+    }
+}
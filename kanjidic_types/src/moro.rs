@@ -1,4 +1,8 @@
+use crate::{shared::NomErrorReason, take_uint, IResult, NomErr};
+use nom::{bytes::complete::take_while, combinator::map_res, sequence::tuple};
 use serde::{Deserialize, Serialize};
+use std::{convert::TryFrom, fmt, str::FromStr};
+use thiserror::Error;
 
 /// An entry in the dictionary Daikanwajiten.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -41,3 +45,80 @@ impl MoroSuffix {
         *self == Self::None
     }
 }
+
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    #[error("(Moro) Unknown index suffix")]
+    UnknownSuffix,
+    #[error("(Moro) Format: {0}")]
+    Format(NomErrorReason),
+}
+
+impl<'a> From<NomErr<'a>> for ParseError {
+    fn from(err: NomErr<'a>) -> Self {
+        Self::Format(err.into())
+    }
+}
+
+impl TryFrom<&str> for Moro {
+    type Error = ParseError;
+
+    /// Parses the index and suffix. The volume and page
+    /// are given separately as attributes in KANJIDIC2.
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        let (_i, (index, suffix)) = parts(text)?;
+        Ok(Moro {
+            volume: None,
+            page: None,
+            index,
+            suffix,
+        })
+    }
+}
+
+impl FromStr for Moro {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for Moro {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let suffix = match self.suffix {
+            MoroSuffix::None => "",
+            MoroSuffix::P => "P",
+            MoroSuffix::X => "X",
+            MoroSuffix::PX => "PX",
+        };
+        write!(f, "{}{}", self.index, suffix)
+    }
+}
+
+fn parts(s: &str) -> IResult<(u16, MoroSuffix)> {
+    tuple((take_uint, suffix))(s)
+}
+
+fn suffix(s: &str) -> IResult<MoroSuffix> {
+    map_res(take_while(|c: char| c.is_ascii_alphabetic()), |v| match v {
+        "X" => Ok(MoroSuffix::X),
+        "P" => Ok(MoroSuffix::P),
+        "PX" => Ok(MoroSuffix::PX),
+        "" => Ok(MoroSuffix::None),
+        _ => Err(ParseError::UnknownSuffix),
+    })(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Moro;
+
+    #[test]
+    fn moro_round_trip() {
+        for text in ["272", "1234PX", "56P"] {
+            let parsed: Moro = text.parse().unwrap();
+            assert_eq!(parsed.to_string(), text);
+        }
+    }
+}
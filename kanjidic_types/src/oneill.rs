@@ -5,7 +5,7 @@ use nom::{
     sequence::tuple,
 };
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fmt, str::FromStr};
 use thiserror::Error;
 
 /// An index into the Japanese Names reference book
@@ -63,6 +63,23 @@ impl TryFrom<&str> for Oneill {
     }
 }
 
+impl FromStr for Oneill {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for Oneill {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.suffix {
+            OneillSuffix::None => write!(f, "{}", self.number),
+            OneillSuffix::A => write!(f, "{}A", self.number),
+        }
+    }
+}
+
 fn parse(s: &str) -> IResult<Oneill> {
     map(parts, |parts| {
         let (number, suffix) = parts;
@@ -81,3 +98,16 @@ fn suffix(s: &str) -> IResult<OneillSuffix> {
         _ => Err(ParseError::UnknownSuffix),
     })(s)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Oneill;
+
+    #[test]
+    fn oneill_round_trip() {
+        for text in ["525", "1234A"] {
+            let parsed: Oneill = text.parse().unwrap();
+            assert_eq!(parsed.to_string(), text);
+        }
+    }
+}
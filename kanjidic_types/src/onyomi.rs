@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An onyomi kanji reading.
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Onyomi {
+    /// The onyomi reading in katakana
+    pub reading: String,
+    /// The period from which the reading was borrowed, if known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<OnyomiKind>,
+    /// Whether the reading is approved for use in the jouyou kanji list
+    pub jouyou: bool,
+}
+
+/// The origin of an onyomi reading.
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum OnyomiKind {
+    /// Borrowed during the Tang dynasty
+    Kan,
+    /// Borrowed from the Wu region before kan readings
+    Go,
+    /// Borrowed during the Song dynasty and later
+    Tou,
+    /// A reading that became accepted through common usage
+    Kanyou,
+    /// A kind not known at the time of writing, as given in the on_type attribute
+    Other(String),
+}
+
+/// Writes the on_type attribute used for the kind in KANJIDIC2.
+impl fmt::Display for OnyomiKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self {
+            OnyomiKind::Kan => "kan",
+            OnyomiKind::Go => "go",
+            OnyomiKind::Tou => "tou",
+            OnyomiKind::Kanyou => "kan'you",
+            OnyomiKind::Other(kind) => kind,
+        };
+        write!(f, "{}", kind)
+    }
+}
@@ -9,7 +9,7 @@ use nom::{
 use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fmt, str::FromStr};
 use thiserror::Error;
 
 // A modern PinYin romanization of the Chinese reading.
@@ -77,6 +77,27 @@ impl TryFrom<&str> for PinYin {
     }
 }
 
+impl FromStr for PinYin {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for PinYin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in self.romanization.chars() {
+            match c {
+                'ü' => f.write_str("u:")?,
+                'ê' => f.write_str("e^")?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        write!(f, "{}", self.tone as u8)
+    }
+}
+
 fn parts(s: &str) -> IResult<(String, u8)> {
     map(pronunciation_parts, |(parts, tone)| (parts.join(""), tone))(s)
 }
@@ -100,3 +121,16 @@ fn special_letter(s: &str) -> IResult<&str> {
 fn letters(s: &str) -> IResult<&str> {
     take_while1(|c: char| c != 'u' && c != 'e' && c.is_ascii_alphabetic())(s)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::PinYin;
+
+    #[test]
+    fn pin_yin_round_trip() {
+        for text in ["ya4", "lu:3", "nu:e4", "e^2"] {
+            let parsed: PinYin = text.parse().unwrap();
+            assert_eq!(parsed.to_string(), text);
+        }
+    }
+}
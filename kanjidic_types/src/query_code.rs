@@ -1,9 +1,10 @@
 use crate::{DeRoo, FourCorner, ShDesc, Skip};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Information relating to a kanji that can be
 /// used for identification and lookup.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(tag = "tag", content = "content")]
 pub enum QueryCode {
     /// The Halpern SKIP code
@@ -16,6 +17,13 @@ pub enum QueryCode {
     DeRoo(DeRoo),
     /// A possible misclassification of the kanji
     Misclassification(Misclassification),
+    /// A code system not known at the time of writing
+    Other {
+        /// The qc_type attribute
+        kind: String,
+        /// The code as it appears in the file
+        value: String,
+    },
 }
 
 /// A possible misclassification of the kanji
@@ -40,3 +48,67 @@ pub enum MisclassificationKind {
     /// Ambiguous stroke counts
     Ambiguous,
 }
+
+impl QueryCode {
+    /// The qc_type attribute used for the code in KANJIDIC2.
+    pub fn kind(&self) -> &str {
+        match self {
+            QueryCode::Skip(_) | QueryCode::Misclassification(_) => "skip",
+            QueryCode::SpahnHadamitzky(_) => "sh_desc",
+            QueryCode::FourCorner(_) => "four_corner",
+            QueryCode::DeRoo(_) => "deroo",
+            QueryCode::Other { kind, .. } => kind,
+        }
+    }
+}
+
+/// Writes the code as it appears in KANJIDIC2.
+/// The kind of a misclassification is an attribute
+/// in the file and is not included.
+impl fmt::Display for QueryCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QueryCode::Skip(skip) => write!(f, "{}", skip),
+            QueryCode::SpahnHadamitzky(descriptor) => write!(f, "{}", descriptor),
+            QueryCode::FourCorner(four_corner) => write!(f, "{}", four_corner),
+            QueryCode::DeRoo(de_roo) => write!(f, "{}", de_roo),
+            QueryCode::Misclassification(misclassification) => {
+                write!(f, "{}", misclassification.skip)
+            }
+            QueryCode::Other { value, .. } => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Writes the skip_misclass attribute used for the kind in KANJIDIC2.
+impl fmt::Display for MisclassificationKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self {
+            MisclassificationKind::Position => "posn",
+            MisclassificationKind::StrokeCount => "stroke_count",
+            MisclassificationKind::StrokeAndPosition => "stroke_and_posn",
+            MisclassificationKind::Ambiguous => "stroke_diff",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::QueryCode;
+    use serde_json::json;
+
+    #[test]
+    fn other_serde_round_trip() {
+        let qc = QueryCode::Other {
+            kind: "new_code".into(),
+            value: "1-2".into(),
+        };
+        let json = serde_json::to_value(&qc).unwrap();
+        assert_eq!(
+            json,
+            json!({"tag": "Other", "content": {"kind": "new_code", "value": "1-2"}})
+        );
+        assert_eq!(serde_json::from_value::<QueryCode>(json).unwrap(), qc);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::KangXi;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// A kanji classification based on its radical.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, Serialize, Deserialize)]
@@ -20,3 +21,14 @@ pub enum RadicalKind {
     /// As used in the classic Modern Japanese-English Character Dictionary.
     Nelson,
 }
+
+/// Writes the rad_type attribute used for the kind in KANJIDIC2.
+impl fmt::Display for RadicalKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self {
+            RadicalKind::Classical => "classical",
+            RadicalKind::Nelson => "nelson_c",
+        };
+        write!(f, "{}", kind)
+    }
+}
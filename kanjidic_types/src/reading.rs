@@ -1,5 +1,6 @@
-use crate::{Kunyomi, PinYin};
+use crate::{Kunyomi, Onyomi, PinYin};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// A particular reading or pronunciation of a kanji.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -14,7 +15,64 @@ pub enum Reading {
     /// The Vietnamese reading supplied by Minh Chau Pham.
     Vietnam(String),
     /// The onyomi reading of the kanji in katakana.
-    Onyomi(String),
+    Onyomi(Onyomi),
     /// The kunyomi reading of the kanji in hiragana or katakana.
     Kunyomi(Kunyomi),
+    /// A kind of reading not known at the time of writing
+    Other {
+        /// The r_type attribute
+        kind: String,
+        /// The reading as it appears in the file
+        value: String,
+    },
+}
+
+impl Reading {
+    /// The r_type attribute used for the reading in KANJIDIC2.
+    pub fn kind(&self) -> &str {
+        match self {
+            Reading::PinYin(_) => "pinyin",
+            Reading::KoreanRomanized(_) => "korean_r",
+            Reading::KoreanHangul(_) => "korean_h",
+            Reading::Vietnam(_) => "vietnam",
+            Reading::Onyomi(_) => "ja_on",
+            Reading::Kunyomi(_) => "ja_kun",
+            Reading::Other { kind, .. } => kind,
+        }
+    }
+}
+
+/// Writes the reading as it appears in KANJIDIC2.
+impl fmt::Display for Reading {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reading::PinYin(pin_yin) => write!(f, "{}", pin_yin),
+            Reading::KoreanRomanized(value)
+            | Reading::KoreanHangul(value)
+            | Reading::Vietnam(value)
+            | Reading::Other { value, .. } => write!(f, "{}", value),
+            Reading::Onyomi(onyomi) => write!(f, "{}", onyomi.reading),
+            Reading::Kunyomi(kunyomi) => write!(f, "{}", kunyomi),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reading;
+    use serde_json::json;
+
+    #[test]
+    fn other_serde_round_trip() {
+        let reading = Reading::Other {
+            kind: "manchu".into(),
+            value: "a".into(),
+        };
+        let json = serde_json::to_value(&reading).unwrap();
+        assert_eq!(
+            json,
+            json!({"tag": "Other", "content": {"kind": "manchu", "value": "a"}})
+        );
+        assert_eq!(serde_json::from_value::<Reading>(json).unwrap(), reading);
+    }
 }
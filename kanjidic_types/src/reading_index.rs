@@ -0,0 +1,150 @@
+use crate::{kana, Character, Reading};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// An index from the Japanese readings of kanji to the kanji,
+/// with all readings stored as hiragana.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadingIndex {
+    /// The kanji with each reading, most frequent first.
+    readings: BTreeMap<String, Vec<char>>,
+}
+
+impl ReadingIndex {
+    /// Indexes the onyomi, kunyomi and nanori of the given characters.
+    /// Kunyomi are indexed both with and without their okurigana.
+    pub fn new(characters: &[Character]) -> Self {
+        let mut readings: BTreeMap<String, Vec<(u16, char)>> = BTreeMap::new();
+        for character in characters {
+            let key = (character.frequency.unwrap_or(u16::MAX), character.literal);
+            let mut add = |reading: &str| {
+                let literals = readings.entry(kana::to_hiragana(reading)).or_default();
+                if !literals.contains(&key) {
+                    literals.push(key);
+                }
+            };
+            for reading in character.readings() {
+                match reading {
+                    Reading::Onyomi(onyomi) => add(&onyomi.reading),
+                    Reading::Kunyomi(kunyomi) => {
+                        add(&kunyomi.reading);
+                        if let Some(okurigana) = &kunyomi.okurigana {
+                            add(&format!("{}{}", kunyomi.reading, okurigana));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            for nanori in character.nanori.iter() {
+                add(nanori);
+            }
+        }
+        let readings = readings
+            .into_iter()
+            .map(|(reading, mut literals)| {
+                literals.sort_unstable();
+                let literals = literals.into_iter().map(|(_, literal)| literal).collect();
+                (reading, literals)
+            })
+            .collect();
+        Self { readings }
+    }
+
+    /// Finds kanji with the given reading, written in hiragana, katakana or
+    /// Hepburn romaji. Okurigana may be separated with a dot as in KANJIDIC2.
+    /// Kanji are ordered by frequency, with those lacking a frequency last.
+    pub fn search(&self, query: &str) -> &[char] {
+        normalize(query)
+            .and_then(|reading| self.readings.get(&reading))
+            .map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Converts a reading to the hiragana used for keys in the index.
+fn normalize(query: &str) -> Option<String> {
+    let query: String = query
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, '.' | '-'))
+        .collect();
+    if query.is_empty() {
+        None
+    } else if query.chars().any(|c| c.is_ascii_alphabetic()) {
+        kana::romaji_to_hiragana(&query)
+    } else {
+        Some(kana::to_hiragana(&query))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReadingIndex;
+    use crate::{
+        character::ReadingMeaningGroup, kunyomi::KunyomiKind, Character, Kunyomi, Onyomi, Reading,
+    };
+
+    fn onyomi(reading: &str) -> Reading {
+        Reading::Onyomi(Onyomi {
+            reading: reading.into(),
+            kind: None,
+            jouyou: true,
+        })
+    }
+
+    fn kunyomi(reading: &str, okurigana: Option<&str>) -> Reading {
+        Reading::Kunyomi(Kunyomi {
+            reading: reading.into(),
+            okurigana: okurigana.map(Into::into),
+            kind: KunyomiKind::Normal,
+            jouyou: true,
+        })
+    }
+
+    fn index() -> ReadingIndex {
+        ReadingIndex::new(&[
+            Character {
+                frequency: Some(1509),
+                reading_meaning_groups: vec![ReadingMeaningGroup::test(
+                    vec![onyomi("ア"), kunyomi("つ", Some("ぐ"))],
+                    &[],
+                )],
+                nanori: vec!["や".into(), "つぐ".into()],
+                ..Character::test('亜')
+            },
+            Character {
+                reading_meaning_groups: vec![ReadingMeaningGroup::test(
+                    vec![onyomi("ア"), onyomi("アク")],
+                    &[],
+                )],
+                ..Character::test('唖')
+            },
+            Character {
+                frequency: Some(1126),
+                reading_meaning_groups: vec![ReadingMeaningGroup::test(vec![onyomi("ア")], &[])],
+                ..Character::test('阿')
+            },
+        ])
+    }
+
+    #[test]
+    fn finds_readings() {
+        let index = index();
+        assert_eq!(index.search("ア"), &['阿', '亜', '唖']);
+        assert_eq!(index.search("あく"), &['唖']);
+        assert_eq!(index.search("つ"), &['亜']);
+        assert_eq!(index.search("つ.ぐ"), &['亜']);
+        assert_eq!(index.search("つぐ"), &['亜']);
+        assert_eq!(index.search("や"), &['亜']);
+        assert!(index.search("い").is_empty());
+        assert!(index.search("").is_empty());
+    }
+
+    #[test]
+    fn finds_romaji() {
+        let index = index();
+        assert_eq!(index.search("aku"), &['唖']);
+        assert_eq!(index.search("Tsugu"), &['亜']);
+        assert!(index.search("xyz").is_empty());
+    }
+}
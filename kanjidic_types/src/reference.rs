@@ -1,8 +1,10 @@
-use crate::{BusyPeople, Moro, Oneill};
+use crate::{busy_people, moro, oneill, BusyPeople, Moro, Oneill};
 use serde::{Deserialize, Serialize};
+use std::{fmt, num::ParseIntError};
+use thiserror::Error;
 
 /// An index number into a particular kanji dictionary or reference book.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(tag = "tag", content = "content")]
 pub enum Reference {
     /// Modern Reader's Japanese-English Dictionary by Andrew Nelson
@@ -53,4 +55,168 @@ pub enum Reference {
     KodanshaCompact(u16),
     /// Les Kanjis dans la tete by Yves Maniette
     Maniette(u16),
+    /// A dictionary not known at the time of writing
+    Other {
+        /// The dr_type attribute
+        kind: String,
+        /// The index as it appears in the file
+        value: String,
+    },
+}
+
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    #[error("(Reference) Index: {0}")]
+    Index(#[from] ParseIntError),
+    #[error("(Reference) Moro: {0}")]
+    Moro(#[from] moro::ParseError),
+    #[error("(Reference) Busy people: {0}")]
+    BusyPeople(#[from] busy_people::ParseError),
+    #[error("(Reference) Oneill: {0}")]
+    Oneill(#[from] oneill::ParseError),
+}
+
+impl Reference {
+    /// Reads an index into the dictionary with the given dr_type,
+    /// written as it appears in KANJIDIC2, such as `1809` for `heisig6`
+    /// or `3.14` for `busy_people`. Morohashi references are read
+    /// without a volume or page.
+    pub fn parse(kind: &str, value: &str) -> Result<Self, ParseError> {
+        let index = || value.parse::<u16>();
+        Ok(match kind {
+            "nelson_c" => Reference::NelsonClassic(index()?),
+            "nelson_n" => Reference::NelsonNew(index()?),
+            "halpern_njecd" => Reference::Njecd(index()?),
+            "halpern_kkd" => Reference::Kkd(index()?),
+            "halpern_kkld" => Reference::Kkld(index()?),
+            "halpern_kkld_2ed" => Reference::Kkld2ed(index()?),
+            "heisig" => Reference::Heisig(index()?),
+            "heisig6" => Reference::Heisig6(index()?),
+            "gakken" => Reference::Gakken(index()?),
+            "oneill_names" => Reference::OneillNames(value.parse()?),
+            "oneill_kk" => Reference::OneillKk(index()?),
+            "moro" => Reference::Moro(value.parse()?),
+            "henshall" => Reference::Henshall(index()?),
+            "sh_kk" => Reference::ShKk(index()?),
+            "sh_kk2" => Reference::ShKk2(index()?),
+            "sakade" => Reference::Sakade(index()?),
+            "jf_cards" => Reference::Jfcards(index()?),
+            "henshall3" => Reference::Henshall3(index()?),
+            "tutt_cards" => Reference::TuttleCards(index()?),
+            "crowley" => Reference::Crowley(index()?),
+            "kanji_in_context" => Reference::KanjiInContext(index()?),
+            "busy_people" => Reference::BusyPeople(value.parse()?),
+            "kodansha_compact" => Reference::KodanshaCompact(index()?),
+            "maniette" => Reference::Maniette(index()?),
+            _ => Reference::Other {
+                kind: kind.into(),
+                value: value.into(),
+            },
+        })
+    }
+
+    /// The dr_type attribute used for the reference in KANJIDIC2.
+    pub fn kind(&self) -> &str {
+        match self {
+            Reference::NelsonClassic(_) => "nelson_c",
+            Reference::NelsonNew(_) => "nelson_n",
+            Reference::Njecd(_) => "halpern_njecd",
+            Reference::Kkd(_) => "halpern_kkd",
+            Reference::Kkld(_) => "halpern_kkld",
+            Reference::Kkld2ed(_) => "halpern_kkld_2ed",
+            Reference::Heisig(_) => "heisig",
+            Reference::Heisig6(_) => "heisig6",
+            Reference::Gakken(_) => "gakken",
+            Reference::OneillNames(_) => "oneill_names",
+            Reference::OneillKk(_) => "oneill_kk",
+            Reference::Moro(_) => "moro",
+            Reference::Henshall(_) => "henshall",
+            Reference::ShKk(_) => "sh_kk",
+            Reference::ShKk2(_) => "sh_kk2",
+            Reference::Sakade(_) => "sakade",
+            Reference::Jfcards(_) => "jf_cards",
+            Reference::Henshall3(_) => "henshall3",
+            Reference::TuttleCards(_) => "tutt_cards",
+            Reference::Crowley(_) => "crowley",
+            Reference::KanjiInContext(_) => "kanji_in_context",
+            Reference::BusyPeople(_) => "busy_people",
+            Reference::KodanshaCompact(_) => "kodansha_compact",
+            Reference::Maniette(_) => "maniette",
+            Reference::Other { kind, .. } => kind,
+        }
+    }
+}
+
+/// Writes the index as it appears in KANJIDIC2.
+/// The volume and page of a Morohashi reference are attributes
+/// in the file and are not included.
+impl fmt::Display for Reference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Reference::NelsonClassic(index)
+            | Reference::NelsonNew(index)
+            | Reference::Njecd(index)
+            | Reference::Kkd(index)
+            | Reference::Kkld(index)
+            | Reference::Kkld2ed(index)
+            | Reference::Heisig(index)
+            | Reference::Heisig6(index)
+            | Reference::Gakken(index)
+            | Reference::OneillKk(index)
+            | Reference::Henshall(index)
+            | Reference::ShKk(index)
+            | Reference::ShKk2(index)
+            | Reference::Sakade(index)
+            | Reference::Jfcards(index)
+            | Reference::Henshall3(index)
+            | Reference::TuttleCards(index)
+            | Reference::Crowley(index)
+            | Reference::KanjiInContext(index)
+            | Reference::KodanshaCompact(index)
+            | Reference::Maniette(index) => write!(f, "{}", index),
+            Reference::OneillNames(oneill) => write!(f, "{}", oneill),
+            Reference::Moro(moro) => write!(f, "{}", moro),
+            Reference::BusyPeople(busy_people) => write!(f, "{}", busy_people),
+            Reference::Other { value, .. } => write!(f, "{}", value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Reference;
+    use serde_json::json;
+
+    #[test]
+    fn reference_round_trip() {
+        for (kind, value) in [
+            ("heisig6", "1809"),
+            ("moro", "272P"),
+            ("busy_people", "3.14"),
+            ("oneill_names", "123A"),
+            ("new_book", "12-b"),
+        ] {
+            let reference = Reference::parse(kind, value).unwrap();
+            assert_eq!(reference.kind(), kind);
+            assert_eq!(reference.to_string(), value);
+        }
+        assert!(Reference::parse("heisig6", "1809A").is_err());
+    }
+
+    #[test]
+    fn other_serde_round_trip() {
+        let reference = Reference::Other {
+            kind: "new_book".into(),
+            value: "12-b".into(),
+        };
+        let json = serde_json::to_value(&reference).unwrap();
+        assert_eq!(
+            json,
+            json!({"tag": "Other", "content": {"kind": "new_book", "value": "12-b"}})
+        );
+        assert_eq!(
+            serde_json::from_value::<Reference>(json).unwrap(),
+            reference
+        );
+    }
 }
@@ -0,0 +1,146 @@
+use crate::{Character, Reference};
+use std::collections::BTreeMap;
+
+/// An index from dictionary references to the kanji that have them,
+/// in the order of each dictionary.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ReferenceIndex {
+    /// The kanji under each reference, grouped by dr_type.
+    references: BTreeMap<String, BTreeMap<Reference, Vec<char>>>,
+}
+
+impl ReferenceIndex {
+    /// Indexes the references of the given characters.
+    pub fn new(characters: &[Character]) -> Self {
+        let mut out = Self::default();
+        for character in characters {
+            for reference in character.references.iter() {
+                let literals = out
+                    .references
+                    .entry(reference.kind().to_owned())
+                    .or_default()
+                    .entry(key(reference))
+                    .or_default();
+                if !literals.contains(&character.literal) {
+                    literals.push(character.literal);
+                }
+            }
+        }
+        out
+    }
+
+    /// The kanji with the given reference.
+    /// Morohashi references match on index alone.
+    pub fn get(&self, reference: &Reference) -> &[char] {
+        self.references
+            .get(reference.kind())
+            .and_then(|references| references.get(&key(reference)))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// The kanji with references from `start` to `end` inclusive,
+    /// in the order they appear in the dictionary.
+    /// Both ends must refer to the same dictionary.
+    pub fn range(&self, start: &Reference, end: &Reference) -> Vec<char> {
+        let (start, end) = (key(start), key(end));
+        if start.kind() != end.kind() || start > end {
+            return vec![];
+        }
+        let mut out = vec![];
+        if let Some(references) = self.references.get(start.kind()) {
+            for literal in references
+                .range(start..=end)
+                .flat_map(|(_, literals)| literals)
+            {
+                if !out.contains(literal) {
+                    out.push(*literal);
+                }
+            }
+        }
+        out
+    }
+
+    /// The dr_types of the indexed references.
+    pub fn kinds(&self) -> impl Iterator<Item = &str> {
+        self.references.keys().map(String::as_str)
+    }
+}
+
+/// Drops the volume and page from Morohashi references,
+/// which are attributes in KANJIDIC2 rather than part of the index.
+fn key(reference: &Reference) -> Reference {
+    match reference {
+        Reference::Moro(moro) => {
+            let mut moro = *moro;
+            moro.volume = None;
+            moro.page = None;
+            Reference::Moro(moro)
+        }
+        reference => reference.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReferenceIndex;
+    use crate::{moro::MoroSuffix, BusyPeople, Character, Moro, Reference};
+
+    fn index() -> ReferenceIndex {
+        ReferenceIndex::new(&[
+            Character {
+                references: vec![
+                    Reference::Heisig6(1616),
+                    Reference::Moro(Moro {
+                        volume: Some(1),
+                        page: Some(525),
+                        index: 272,
+                        suffix: MoroSuffix::None,
+                    }),
+                ],
+                ..Character::test('亜')
+            },
+            Character {
+                references: vec![
+                    Reference::Heisig6(1),
+                    Reference::BusyPeople(BusyPeople {
+                        volume: 1,
+                        chapter: Some(4),
+                    }),
+                ],
+                ..Character::test('一')
+            },
+            Character {
+                references: vec![Reference::Heisig6(1218)],
+                ..Character::test('円')
+            },
+        ])
+    }
+
+    #[test]
+    fn gets_references() {
+        let index = index();
+        let get = |kind, value| index.get(&Reference::parse(kind, value).unwrap()).to_vec();
+        assert_eq!(get("heisig6", "1616"), vec!['亜']);
+        assert_eq!(get("moro", "272"), vec!['亜']);
+        assert_eq!(get("busy_people", "1.4"), vec!['一']);
+        assert!(get("heisig6", "2").is_empty());
+        assert!(get("heisig", "1616").is_empty());
+    }
+
+    #[test]
+    fn ranges() {
+        let index = index();
+        let range = |start, end| {
+            index.range(
+                &Reference::parse("heisig6", start).unwrap(),
+                &Reference::parse("heisig6", end).unwrap(),
+            )
+        };
+        assert_eq!(range("1", "1616"), vec!['一', '円', '亜']);
+        assert_eq!(range("2", "1500"), vec!['円']);
+        assert!(range("1616", "1").is_empty());
+        assert!(index
+            .range(&Reference::Heisig6(1), &Reference::Heisig(1616))
+            .is_empty());
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{pin_yin::Tone, Character, Reading};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, convert::TryFrom};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// An index from the Chinese, Korean and Vietnamese readings of kanji
+/// to the kanji.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SinoIndex {
+    /// Kanji by pinyin with the tone number, as in `ya4`.
+    pin_yin_toned: BTreeMap<String, Vec<char>>,
+    /// Kanji by pinyin without the tone.
+    pin_yin: BTreeMap<String, Vec<char>>,
+    /// Kanji by hangul and by revised romanization.
+    korean: BTreeMap<String, Vec<char>>,
+    /// Kanji by Hán-Việt reading without diacritics.
+    vietnam: BTreeMap<String, Vec<char>>,
+}
+
+impl SinoIndex {
+    /// Indexes the pinyin, Korean and Vietnamese readings of the given characters.
+    pub fn new(characters: &[Character]) -> Self {
+        let mut pin_yin_toned = Builder::default();
+        let mut pin_yin = Builder::default();
+        let mut korean = Builder::default();
+        let mut vietnam = Builder::default();
+        for character in characters {
+            for reading in character.readings() {
+                match reading {
+                    Reading::PinYin(reading) => {
+                        let toned = format!("{}{}", reading.romanization, reading.tone as u8);
+                        pin_yin_toned.add(toned, character);
+                        pin_yin.add(reading.romanization.clone(), character);
+                    }
+                    Reading::KoreanRomanized(reading) => korean.add(romanized(reading), character),
+                    Reading::KoreanHangul(reading) => {
+                        korean.add(reading.nfc().collect(), character)
+                    }
+                    Reading::Vietnam(reading) => vietnam.add(fold(reading), character),
+                    _ => {}
+                }
+            }
+        }
+        Self {
+            pin_yin_toned: pin_yin_toned.build(),
+            pin_yin: pin_yin.build(),
+            korean: korean.build(),
+            vietnam: vietnam.build(),
+        }
+    }
+
+    /// Finds kanji by pinyin, written with tone numbers as in `ya4`
+    /// or with tone marks as in `yà`. The tone is ignored when left out.
+    /// `ü` may also be written as `u:` or `v`.
+    pub fn pin_yin(&self, query: &str) -> &[char] {
+        let (romanization, tone) = pin_yin(query);
+        match tone {
+            Some(tone) => get(
+                &self.pin_yin_toned,
+                &format!("{}{}", romanization, tone as u8),
+            ),
+            None => get(&self.pin_yin, &romanization),
+        }
+    }
+
+    /// Finds kanji by Korean reading, in hangul or revised romanization.
+    pub fn korean(&self, query: &str) -> &[char] {
+        let query = query.trim();
+        if query.chars().any(|c| c.is_ascii_alphabetic()) {
+            get(&self.korean, &romanized(query))
+        } else {
+            get(&self.korean, &query.nfc().collect::<String>())
+        }
+    }
+
+    /// Finds kanji by Hán-Việt reading, ignoring diacritics and case.
+    pub fn vietnam(&self, query: &str) -> &[char] {
+        get(&self.vietnam, &fold(query.trim()))
+    }
+}
+
+/// Collects the kanji under each key, most frequent first.
+#[derive(Default)]
+struct Builder(BTreeMap<String, Vec<(u16, char)>>);
+
+impl Builder {
+    fn add(&mut self, key: String, character: &Character) {
+        let entry = (character.frequency.unwrap_or(u16::MAX), character.literal);
+        let literals = self.0.entry(key).or_default();
+        if !literals.contains(&entry) {
+            literals.push(entry);
+        }
+    }
+
+    fn build(self) -> BTreeMap<String, Vec<char>> {
+        self.0
+            .into_iter()
+            .map(|(key, mut literals)| {
+                literals.sort_unstable();
+                (
+                    key,
+                    literals.into_iter().map(|(_, literal)| literal).collect(),
+                )
+            })
+            .collect()
+    }
+}
+
+fn get<'a>(map: &'a BTreeMap<String, Vec<char>>, key: &str) -> &'a [char] {
+    map.get(key).map_or(&[], Vec::as_slice)
+}
+
+/// Splits pinyin into the romanization as stored in [`PinYin`](crate::PinYin)
+/// and the tone, if given.
+fn pin_yin(query: &str) -> (String, Option<Tone>) {
+    let mut romanization = String::new();
+    let mut tone = None;
+    let query = query
+        .trim()
+        .to_lowercase()
+        .replace("u:", "ü")
+        .replace("e^", "ê");
+    for c in query.nfd() {
+        match c {
+            '\u{304}' => tone = Some(Tone::High),
+            '\u{301}' => tone = Some(Tone::Rising),
+            '\u{30C}' => tone = Some(Tone::Low),
+            '\u{300}' => tone = Some(Tone::Falling),
+            '\u{308}' if romanization.ends_with('u') => {
+                romanization.pop();
+                romanization.push('ü');
+            }
+            '\u{302}' if romanization.ends_with('e') => {
+                romanization.pop();
+                romanization.push('ê');
+            }
+            'v' => romanization.push('ü'),
+            '1'..='5' => tone = c.to_digit(10).and_then(|d| Tone::try_from(d as u8).ok()),
+            c => romanization.push(c),
+        }
+    }
+    (romanization, tone)
+}
+
+/// Lowercases a revised romanization and drops separators.
+fn romanized(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Lowercases text and removes diacritics, treating `đ` as `d`.
+fn fold(text: &str) -> String {
+    text.nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+        .map(|c| if c == 'đ' { 'd' } else { c })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SinoIndex;
+    use crate::{character::ReadingMeaningGroup, Character, Reading};
+
+    fn pin_yin(reading: &str) -> Reading {
+        Reading::PinYin(reading.parse().unwrap())
+    }
+
+    fn index() -> SinoIndex {
+        SinoIndex::new(&[
+            Character {
+                frequency: Some(1509),
+                reading_meaning_groups: vec![ReadingMeaningGroup::test(
+                    vec![
+                        pin_yin("ya4"),
+                        Reading::KoreanRomanized("a".into()),
+                        Reading::KoreanHangul("아".into()),
+                        Reading::Vietnam("A".into()),
+                        Reading::Vietnam("Á".into()),
+                    ],
+                    &[],
+                )],
+                ..Character::test('亜')
+            },
+            Character {
+                reading_meaning_groups: vec![ReadingMeaningGroup::test(
+                    vec![pin_yin("ya1"), pin_yin("ya3")],
+                    &[],
+                )],
+                ..Character::test('唖')
+            },
+            Character {
+                frequency: Some(151),
+                reading_meaning_groups: vec![ReadingMeaningGroup::test(
+                    vec![pin_yin("nu:3"), Reading::Vietnam("Nữ".into())],
+                    &[],
+                )],
+                ..Character::test('女')
+            },
+            Character {
+                reading_meaning_groups: vec![ReadingMeaningGroup::test(
+                    vec![Reading::Vietnam("Đồ".into())],
+                    &[],
+                )],
+                ..Character::test('図')
+            },
+        ])
+    }
+
+    #[test]
+    fn finds_pin_yin() {
+        let index = index();
+        assert_eq!(index.pin_yin("ya4"), &['亜']);
+        assert_eq!(index.pin_yin("yà"), &['亜']);
+        assert_eq!(index.pin_yin("yā"), &['唖']);
+        assert_eq!(index.pin_yin("ya"), &['亜', '唖']);
+        assert!(index.pin_yin("ya2").is_empty());
+        for query in ["nu:3", "nǚ", "nü", "nv3"] {
+            assert_eq!(index.pin_yin(query), &['女'], "{}", query);
+        }
+    }
+
+    #[test]
+    fn finds_korean() {
+        let index = index();
+        assert_eq!(index.korean("아"), &['亜']);
+        assert_eq!(index.korean("\u{110b}\u{1161}"), &['亜']);
+        assert_eq!(index.korean("A"), &['亜']);
+        assert!(index.korean("o").is_empty());
+    }
+
+    #[test]
+    fn finds_vietnam() {
+        let index = index();
+        assert_eq!(index.vietnam("a"), &['亜']);
+        assert_eq!(index.vietnam("á"), &['亜']);
+        assert_eq!(index.vietnam("nu"), &['女']);
+        assert_eq!(index.vietnam("do"), &['図']);
+    }
+}
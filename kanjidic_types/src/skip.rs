@@ -3,7 +3,7 @@ use nom::{character::complete::char, sequence::tuple};
 use num_enum::TryFromPrimitive;
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fmt, str::FromStr};
 use thiserror::Error;
 
 /// Kanji code from the SKIP system of indexing.
@@ -133,6 +133,42 @@ impl TryFrom<&str> for Skip {
     }
 }
 
+impl FromStr for Skip {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for Skip {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Horizontal(skip) => write!(f, "1-{}-{}", skip.left, skip.right),
+            Self::Vertical(skip) => write!(f, "2-{}-{}", skip.top, skip.bottom),
+            Self::Enclosure(skip) => write!(f, "3-{}-{}", skip.exterior, skip.interior),
+            Self::Solid(skip) => write!(
+                f,
+                "4-{}-{}",
+                skip.total_stroke_count, skip.solid_subpattern as u8
+            ),
+        }
+    }
+}
+
 fn parts(s: &str) -> IResult<(u8, char, u8, char, u8)> {
     tuple((take_uint, char('-'), take_uint, char('-'), take_uint))(s)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Skip;
+
+    #[test]
+    fn skip_round_trip() {
+        for text in ["1-2-3", "2-4-5", "3-3-7", "4-7-1"] {
+            let parsed: Skip = text.parse().unwrap();
+            assert_eq!(parsed.to_string(), text);
+        }
+    }
+}
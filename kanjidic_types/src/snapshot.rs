@@ -0,0 +1,113 @@
+use crate::{Character, MeaningIndex, ReadingIndex, SinoIndex};
+use serde::{Deserialize, Serialize};
+use std::io::{self, Read};
+use thiserror::Error;
+
+/// Bytes at the start of every snapshot file.
+pub const MAGIC: &[u8; 8] = b"KANJIDIC";
+
+/// The layout version of snapshots written by this crate.
+/// Bumped whenever the encoded structure changes.
+pub const VERSION: u16 = 4;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("(Snapshot) Not a snapshot file")]
+    Magic,
+    #[error("(Snapshot) Unsupported snapshot version {0}, expected {VERSION}")]
+    Version(u16),
+    #[error("(Snapshot) Encoding: {0}")]
+    Encode(#[from] rmp_serde::encode::Error),
+    #[error("(Snapshot) Decoding: {0}")]
+    Decode(#[from] rmp_serde::decode::Error),
+    #[error("(Snapshot) Reading: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// The characters of a KANJIDIC2 file along with prebuilt lookup indexes,
+/// in a compact binary form that loads without parsing JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    /// All the characters in the file.
+    pub characters: Vec<Character>,
+    /// Search from the words of meanings to kanji.
+    pub meanings: MeaningIndex,
+    /// Search from hiragana readings to kanji.
+    pub readings: ReadingIndex,
+    /// Search from Chinese, Korean and Vietnamese readings to kanji.
+    pub sino: SinoIndex,
+}
+
+impl Snapshot {
+    /// Builds the indexes for the given characters.
+    pub fn new(characters: Vec<Character>) -> Self {
+        let meanings = MeaningIndex::new(&characters);
+        let readings = ReadingIndex::new(&characters);
+        let sino = SinoIndex::new(&characters);
+        Self {
+            characters,
+            meanings,
+            readings,
+            sino,
+        }
+    }
+
+    /// Whether the bytes begin like a snapshot file.
+    pub fn is_snapshot(bytes: &[u8]) -> bool {
+        bytes.starts_with(MAGIC)
+    }
+
+    /// Encodes the snapshot, prefixed with the magic bytes and version.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut out = MAGIC.to_vec();
+        out.extend_from_slice(&VERSION.to_le_bytes());
+        rmp_serde::encode::write_named(&mut out, self)?;
+        Ok(out)
+    }
+
+    /// Decodes a snapshot produced by [`Snapshot::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_reader(bytes)
+    }
+
+    /// Decodes a snapshot produced by [`Snapshot::to_bytes`]
+    /// as it is read, without holding the encoded bytes in memory.
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, Error> {
+        let mut header = [0; MAGIC.len() + 2];
+        match reader.read_exact(&mut header) {
+            Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Err(Error::Magic),
+            result => result?,
+        }
+        if !Self::is_snapshot(&header) {
+            return Err(Error::Magic);
+        }
+        let version = u16::from_le_bytes([header[MAGIC.len()], header[MAGIC.len() + 1]]);
+        if version != VERSION {
+            return Err(Error::Version(version));
+        }
+        Ok(rmp_serde::from_read(reader)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, Snapshot, MAGIC, VERSION};
+    use crate::Character;
+
+    #[test]
+    fn round_trip() {
+        let snapshot = Snapshot::new(vec![Character::sample()]);
+        assert_eq!(snapshot.meanings.search("rank", &[])[0].literal, '亜');
+        let bytes = snapshot.to_bytes().unwrap();
+        assert_eq!(Snapshot::from_bytes(&bytes).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let mut bytes = Snapshot::new(vec![]).to_bytes().unwrap();
+        bytes[MAGIC.len()] += 1;
+        assert!(matches!(Snapshot::from_bytes(&bytes), Err(Error::Version(v)) if v == VERSION + 1));
+        assert!(matches!(Snapshot::from_bytes(b"[]"), Err(Error::Magic)));
+    }
+}
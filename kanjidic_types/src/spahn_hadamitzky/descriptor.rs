@@ -2,7 +2,7 @@ use super::radical::Radical;
 use crate::{shared::NomErrorReason, take_uint, IResult, NomErr};
 use nom::{bytes::complete::take, character::complete::char, combinator::map_res, sequence::tuple};
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fmt, str::FromStr};
 use thiserror::Error;
 
 /// Descriptor code for The Kanji Dictionary.
@@ -47,6 +47,24 @@ impl TryFrom<&str> for Descriptor {
     }
 }
 
+impl FromStr for Descriptor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl fmt::Display for Descriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}.{}",
+            self.radical_strokes, self.radical, self.other_strokes, self.sequence
+        )
+    }
+}
+
 fn parts(s: &str) -> IResult<(u8, Radical, u8, char, u8)> {
     tuple((take_uint, radical, take_uint, char('.'), take_uint))(s)
 }
@@ -57,3 +75,16 @@ fn radical(s: &str) -> IResult<Radical> {
         Radical::try_from(c)
     })(s)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Descriptor;
+
+    #[test]
+    fn descriptor_round_trip() {
+        for text in ["0a7.14", "2k10.3"] {
+            let parsed: Descriptor = text.parse().unwrap();
+            assert_eq!(parsed.to_string(), text);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{convert::TryFrom, fmt};
 use thiserror::Error;
 
 /// An identifying radical in the Spahn and Hadamitzky classification system.
@@ -75,3 +75,37 @@ impl TryFrom<char> for Radical {
         }
     }
 }
+
+impl fmt::Display for Radical {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let letter = match self {
+            Radical::A => 'a',
+            Radical::B => 'b',
+            Radical::C => 'c',
+            Radical::D => 'd',
+            Radical::E => 'e',
+            Radical::F => 'f',
+            Radical::G => 'g',
+            Radical::H => 'h',
+            Radical::I => 'i',
+            Radical::J => 'j',
+            Radical::K => 'k',
+            Radical::L => 'l',
+            Radical::M => 'm',
+            Radical::N => 'n',
+            Radical::O => 'o',
+            Radical::P => 'p',
+            Radical::Q => 'q',
+            Radical::R => 'r',
+            Radical::S => 's',
+            Radical::T => 't',
+            Radical::U => 'u',
+            Radical::V => 'v',
+            Radical::W => 'w',
+            Radical::X => 'x',
+            Radical::Y => 'y',
+            Radical::Z => 'z',
+        };
+        write!(f, "{}", letter)
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{DeRoo, Kuten, Oneill, ShDesc};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Represents either of the following:
 /// - A cross-reference to another kanji usually regarded as a variant
 /// - An alternative indexing code for the current kanji
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(tag = "tag", content = "content")]
 pub enum Variant {
     /// A coding in JIS X 0208
@@ -25,4 +26,65 @@ pub enum Variant {
     Nelson(u16),
     /// Index in Japanese Names by P.G. O'Neill.
     ONeill(Oneill),
+    /// A coding not known at the time of writing
+    Other {
+        /// The var_type attribute
+        kind: String,
+        /// The code as it appears in the file
+        value: String,
+    },
+}
+
+impl Variant {
+    /// The var_type attribute used for the variant in KANJIDIC2.
+    pub fn kind(&self) -> &str {
+        match self {
+            Variant::Jis208(_) => "jis208",
+            Variant::Jis212(_) => "jis212",
+            Variant::Jis213(_) => "jis213",
+            Variant::Unicode(_) => "ucs",
+            Variant::DeRoo(_) => "deroo",
+            Variant::Halpern(_) => "njecd",
+            Variant::SpahnHadamitzky(_) => "s_h",
+            Variant::Nelson(_) => "nelson_c",
+            Variant::ONeill(_) => "oneill",
+            Variant::Other { kind, .. } => kind,
+        }
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Variant::Jis208(kuten) | Variant::Jis212(kuten) | Variant::Jis213(kuten) => {
+                write!(f, "{}", kuten)
+            }
+            Variant::Unicode(code) => write!(f, "{:x}", code),
+            Variant::DeRoo(de_roo) => write!(f, "{}", de_roo),
+            Variant::Halpern(index) | Variant::Nelson(index) => write!(f, "{}", index),
+            Variant::SpahnHadamitzky(descriptor) => write!(f, "{}", descriptor),
+            Variant::ONeill(oneill) => write!(f, "{}", oneill),
+            Variant::Other { value, .. } => write!(f, "{}", value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Variant;
+    use serde_json::json;
+
+    #[test]
+    fn other_serde_round_trip() {
+        let variant = Variant::Other {
+            kind: "new_var".into(),
+            value: "3A21".into(),
+        };
+        let json = serde_json::to_value(&variant).unwrap();
+        assert_eq!(
+            json,
+            json!({"tag": "Other", "content": {"kind": "new_var", "value": "3A21"}})
+        );
+        assert_eq!(serde_json::from_value::<Variant>(json).unwrap(), variant);
+    }
 }